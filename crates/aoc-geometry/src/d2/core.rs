@@ -1,2 +1,7 @@
 pub mod direction_2d;
 pub mod cardinal_direction_2d;
+pub mod flat_hex_direction_2d;
+pub mod hex_coordinate_2d;
+pub mod orientation_2d;
+pub mod parse_direction_error;
+pub mod pointy_hex_direction_2d;
@@ -1,4 +1,8 @@
+#[cfg(test)]
+mod cardinal_direction_2d_tests;
+
 use crate::Vector;
+use crate::d2::core::parse_direction_error::ParseDirectionError;
 use crate::generic::core::vector_coordinate::VectorCoordinate;
 
 const DIMENSIONS: usize = 2;
@@ -12,6 +16,14 @@ pub enum CardinalDirection2D {
 }
 
 impl CardinalDirection2D {
+    /// All the directions, in clockwise order starting from `Up`.
+    pub const ALL: [Self; 4] = [
+        CardinalDirection2D::Up,
+        CardinalDirection2D::Right,
+        CardinalDirection2D::Down,
+        CardinalDirection2D::Left,
+    ];
+
     pub fn rotate_clockwise(self) -> Self {
         match self {
             CardinalDirection2D::Up => CardinalDirection2D::Right,
@@ -30,6 +42,33 @@ impl CardinalDirection2D {
         }
     }
 
+    /// Returns the direction after turning 90 degrees to the left, from the point of view of
+    /// someone facing this direction.
+    pub fn turn_left(self) -> Self {
+        self.rotate_counter_clockwise()
+    }
+
+    /// Returns the direction after turning 90 degrees to the right, from the point of view of
+    /// someone facing this direction.
+    pub fn turn_right(self) -> Self {
+        self.rotate_clockwise()
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        -self
+    }
+
+    /// Checks if the direction is along the horizontal axis (`Left` or `Right`).
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, CardinalDirection2D::Left | CardinalDirection2D::Right)
+    }
+
+    /// Checks if the direction is along the vertical axis (`Up` or `Down`).
+    pub fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+
     pub fn to_vector<T>(&self) -> Vector<T, DIMENSIONS>
     where
         T: VectorCoordinate,
@@ -55,3 +94,47 @@ impl std::ops::Neg for CardinalDirection2D {
         }
     }
 }
+
+/// Converts a single character into a direction.
+///
+/// The supported characters are:
+/// * `U`, `D`, `L`, `R`
+/// * `N`, `S`, `W`, `E`
+/// * `^`, `v`, `<`, `>`
+///
+/// Letters are case-insensitive.
+impl TryFrom<char> for CardinalDirection2D {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'U' | 'N' | '^' => Ok(CardinalDirection2D::Up),
+            'D' | 'S' | 'V' => Ok(CardinalDirection2D::Down),
+            'L' | 'W' | '<' => Ok(CardinalDirection2D::Left),
+            'R' | 'E' | '>' => Ok(CardinalDirection2D::Right),
+            _ => Err(ParseDirectionError::new(&c.to_string())),
+        }
+    }
+}
+
+/// Parses a direction from a single character (see `TryFrom<char>`) or from its full name:
+/// `up`, `down`, `left`, `right`, `north`, `south`, `west` or `east`.
+///
+/// The parsing is case-insensitive.
+impl std::str::FromStr for CardinalDirection2D {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return CardinalDirection2D::try_from(c).map_err(|_| ParseDirectionError::new(s));
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "up" | "north" => Ok(CardinalDirection2D::Up),
+            "down" | "south" => Ok(CardinalDirection2D::Down),
+            "left" | "west" => Ok(CardinalDirection2D::Left),
+            "right" | "east" => Ok(CardinalDirection2D::Right),
+            _ => Err(ParseDirectionError::new(s)),
+        }
+    }
+}
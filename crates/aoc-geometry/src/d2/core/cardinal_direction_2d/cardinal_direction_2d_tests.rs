@@ -0,0 +1,127 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+// Tests for rotations and turns
+
+#[test]
+fn rotate_clockwise_follows_all_order() {
+    for (i, direction) in CardinalDirection2D::ALL.iter().enumerate() {
+        assert_eq!(
+            direction.rotate_clockwise(),
+            CardinalDirection2D::ALL[(i + 1) % 4]
+        );
+    }
+}
+
+#[test]
+fn rotate_counter_clockwise_is_inverse() {
+    for direction in CardinalDirection2D::ALL {
+        assert_eq!(
+            direction.rotate_clockwise().rotate_counter_clockwise(),
+            direction
+        );
+    }
+}
+
+#[test]
+fn turns() {
+    assert_eq!(
+        CardinalDirection2D::Up.turn_left(),
+        CardinalDirection2D::Left
+    );
+    assert_eq!(
+        CardinalDirection2D::Up.turn_right(),
+        CardinalDirection2D::Right
+    );
+    assert_eq!(
+        CardinalDirection2D::Left.turn_left(),
+        CardinalDirection2D::Down
+    );
+    assert_eq!(
+        CardinalDirection2D::Left.turn_right(),
+        CardinalDirection2D::Up
+    );
+    for direction in CardinalDirection2D::ALL {
+        assert_eq!(direction.reverse(), -direction);
+        assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+    }
+}
+
+#[test]
+fn is_horizontal_and_vertical() {
+    assert!(CardinalDirection2D::Left.is_horizontal());
+    assert!(CardinalDirection2D::Right.is_horizontal());
+    assert!(CardinalDirection2D::Up.is_vertical());
+    assert!(CardinalDirection2D::Down.is_vertical());
+    assert!(!CardinalDirection2D::Up.is_horizontal());
+}
+
+// Tests for to_vector
+
+#[test]
+fn to_vector() {
+    assert_eq!(
+        CardinalDirection2D::Up.to_vector::<i32>(),
+        Vector::new([0, 1])
+    );
+    assert_eq!(
+        CardinalDirection2D::Down.to_vector::<i32>(),
+        Vector::new([0, -1])
+    );
+    assert_eq!(
+        CardinalDirection2D::Left.to_vector::<i32>(),
+        Vector::new([-1, 0])
+    );
+    assert_eq!(
+        CardinalDirection2D::Right.to_vector::<i32>(),
+        Vector::new([1, 0])
+    );
+}
+
+#[test]
+fn to_vector_of_rotation_is_rotated_vector() {
+    for direction in CardinalDirection2D::ALL {
+        let v = direction.to_vector::<i32>();
+        let rotated = direction.rotate_clockwise().to_vector::<i32>();
+        // a clockwise rotation maps (x, y) to (y, -x)
+        assert_eq!(rotated, Vector::new([v[1], -v[0]]));
+    }
+}
+
+// Tests for parsing
+
+#[test]
+fn try_from_char() {
+    for (chars, expected) in [
+        (['U', 'N', '^', 'u'], CardinalDirection2D::Up),
+        (['D', 'S', 'v', 'd'], CardinalDirection2D::Down),
+        (['L', 'W', '<', 'l'], CardinalDirection2D::Left),
+        (['R', 'E', '>', 'r'], CardinalDirection2D::Right),
+    ] {
+        for c in chars {
+            assert_eq!(CardinalDirection2D::try_from(c), Ok(expected));
+        }
+    }
+}
+
+#[test]
+fn try_from_char_invalid() {
+    let error = CardinalDirection2D::try_from('x').unwrap_err();
+    assert_eq!(error.input(), "x");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("U".parse(), Ok(CardinalDirection2D::Up));
+    assert_eq!(">".parse(), Ok(CardinalDirection2D::Right));
+    assert_eq!("down".parse(), Ok(CardinalDirection2D::Down));
+    assert_eq!("West".parse(), Ok(CardinalDirection2D::Left));
+}
+
+#[test]
+fn from_str_invalid() {
+    assert!("".parse::<CardinalDirection2D>().is_err());
+    assert!("UR".parse::<CardinalDirection2D>().is_err());
+    let error = "forward".parse::<CardinalDirection2D>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid direction: \"forward\"");
+}
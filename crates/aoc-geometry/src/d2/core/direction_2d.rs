@@ -1,4 +1,9 @@
+#[cfg(test)]
+mod direction_2d_tests;
+
+use crate::CardinalDirection2D;
 use crate::Vector;
+use crate::d2::core::parse_direction_error::ParseDirectionError;
 use crate::generic::core::vector_coordinate::VectorCoordinate;
 
 const DIMENSIONS: usize = 2;
@@ -16,6 +21,86 @@ pub enum Direction2D {
 }
 
 impl Direction2D {
+    /// All the directions, in clockwise order starting from `Up`.
+    pub const ALL: [Self; 8] = [
+        Direction2D::Up,
+        Direction2D::UpRight,
+        Direction2D::Right,
+        Direction2D::DownRight,
+        Direction2D::Down,
+        Direction2D::DownLeft,
+        Direction2D::Left,
+        Direction2D::UpLeft,
+    ];
+
+    /// The axis-aligned directions, in clockwise order starting from `Up`.
+    pub const ORTHOGONAL: [Self; 4] = [
+        Direction2D::Up,
+        Direction2D::Right,
+        Direction2D::Down,
+        Direction2D::Left,
+    ];
+
+    /// The diagonal directions, in clockwise order starting from `UpRight`.
+    pub const DIAGONAL: [Self; 4] = [
+        Direction2D::UpRight,
+        Direction2D::DownRight,
+        Direction2D::DownLeft,
+        Direction2D::UpLeft,
+    ];
+
+    /// Gets the position of this direction inside `ALL`.
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    /// Rotates the direction clockwise by a number of 45-degree steps.
+    fn rotate_steps(self, steps: usize) -> Self {
+        Self::ALL[(self.index() + steps) % Self::ALL.len()]
+    }
+
+    /// Returns the direction after rotating 90 degrees clockwise.
+    pub fn rotate_clockwise(self) -> Self {
+        self.rotate_steps(2)
+    }
+
+    /// Returns the direction after rotating 90 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.rotate_steps(6)
+    }
+
+    /// Returns the direction after rotating 45 degrees clockwise.
+    pub fn rotate_clockwise_45(self) -> Self {
+        self.rotate_steps(1)
+    }
+
+    /// Returns the direction after rotating 45 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise_45(self) -> Self {
+        self.rotate_steps(7)
+    }
+
+    /// Returns the direction after turning 90 degrees to the left, from the point of view of
+    /// someone facing this direction.
+    pub fn turn_left(self) -> Self {
+        self.rotate_counter_clockwise()
+    }
+
+    /// Returns the direction after turning 90 degrees to the right, from the point of view of
+    /// someone facing this direction.
+    pub fn turn_right(self) -> Self {
+        self.rotate_clockwise()
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        -self
+    }
+
+    /// Checks if the direction is one of the diagonals.
+    pub fn is_diagonal(&self) -> bool {
+        Self::DIAGONAL.contains(self)
+    }
+
     pub fn to_vector<T>(&self) -> Vector<T, DIMENSIONS>
     where
         T: VectorCoordinate,
@@ -25,8 +110,8 @@ impl Direction2D {
             Direction2D::UpLeft => Vector::new([-T::one(), T::one()]),
             Direction2D::UpRight => Vector::new([T::one(), T::one()]),
             Direction2D::Down => Vector::new([T::zero(), -T::one()]),
-            Direction2D::DownLeft => Vector::new([-T::one(), T::one()]),
-            Direction2D::DownRight => Vector::new([T::one(), T::one()]),
+            Direction2D::DownLeft => Vector::new([-T::one(), -T::one()]),
+            Direction2D::DownRight => Vector::new([T::one(), -T::one()]),
             Direction2D::Left => Vector::new([-T::one(), T::zero()]),
             Direction2D::Right => Vector::new([T::one(), T::zero()]),
         }
@@ -49,3 +134,46 @@ impl std::ops::Neg for Direction2D {
         }
     }
 }
+
+impl From<CardinalDirection2D> for Direction2D {
+    fn from(direction: CardinalDirection2D) -> Self {
+        match direction {
+            CardinalDirection2D::Up => Direction2D::Up,
+            CardinalDirection2D::Down => Direction2D::Down,
+            CardinalDirection2D::Left => Direction2D::Left,
+            CardinalDirection2D::Right => Direction2D::Right,
+        }
+    }
+}
+
+/// Converts a single character into an axis-aligned direction.
+///
+/// The supported characters are the same as for `CardinalDirection2D`.
+impl TryFrom<char> for Direction2D {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        CardinalDirection2D::try_from(c).map(Direction2D::from)
+    }
+}
+
+/// Parses a direction from any of the formats supported by `CardinalDirection2D`, or from
+/// the abbreviation of a diagonal: `UL`, `UR`, `DL`, `DR`, `NW`, `NE`, `SW` or `SE`.
+///
+/// The parsing is case-insensitive.
+impl std::str::FromStr for Direction2D {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(direction) = s.parse::<CardinalDirection2D>() {
+            return Ok(direction.into());
+        }
+        match s.to_ascii_uppercase().as_str() {
+            "UL" | "NW" => Ok(Direction2D::UpLeft),
+            "UR" | "NE" => Ok(Direction2D::UpRight),
+            "DL" | "SW" => Ok(Direction2D::DownLeft),
+            "DR" | "SE" => Ok(Direction2D::DownRight),
+            _ => Err(ParseDirectionError::new(s)),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+// Tests for to_vector
+
+#[test]
+fn to_vector() {
+    assert_eq!(Direction2D::Up.to_vector::<i32>(), Vector::new([0, 1]));
+    assert_eq!(Direction2D::UpRight.to_vector::<i32>(), Vector::new([1, 1]));
+    assert_eq!(Direction2D::Right.to_vector::<i32>(), Vector::new([1, 0]));
+    assert_eq!(
+        Direction2D::DownRight.to_vector::<i32>(),
+        Vector::new([1, -1])
+    );
+    assert_eq!(Direction2D::Down.to_vector::<i32>(), Vector::new([0, -1]));
+    assert_eq!(
+        Direction2D::DownLeft.to_vector::<i32>(),
+        Vector::new([-1, -1])
+    );
+    assert_eq!(Direction2D::Left.to_vector::<i32>(), Vector::new([-1, 0]));
+    assert_eq!(Direction2D::UpLeft.to_vector::<i32>(), Vector::new([-1, 1]));
+}
+
+#[test]
+fn to_vector_of_opposite_is_negated() {
+    for direction in Direction2D::ALL {
+        assert_eq!(
+            (-direction).to_vector::<i32>(),
+            (-direction.to_vector::<i32>()).unwrap()
+        );
+    }
+}
+
+#[test]
+fn to_vector_is_unique() {
+    let vectors: std::collections::HashSet<_> = Direction2D::ALL
+        .iter()
+        .map(|d| d.to_vector::<i8>())
+        .collect();
+    assert_eq!(vectors.len(), 8);
+}
+
+#[test]
+fn to_vector_matches_cardinal_direction() {
+    for direction in CardinalDirection2D::ALL {
+        assert_eq!(
+            Direction2D::from(direction).to_vector::<i32>(),
+            direction.to_vector::<i32>()
+        );
+    }
+}
+
+// Tests for constants
+
+#[test]
+fn orthogonal_and_diagonal_partition_all() {
+    for direction in Direction2D::ALL {
+        assert_ne!(
+            Direction2D::ORTHOGONAL.contains(&direction),
+            Direction2D::DIAGONAL.contains(&direction)
+        );
+        assert_eq!(
+            direction.is_diagonal(),
+            direction.to_vector::<i32>().is_diagonal()
+        );
+    }
+}
+
+// Tests for rotations and turns
+
+#[test]
+fn rotate_clockwise_45_follows_all_order() {
+    for (i, direction) in Direction2D::ALL.iter().enumerate() {
+        assert_eq!(
+            direction.rotate_clockwise_45(),
+            Direction2D::ALL[(i + 1) % 8]
+        );
+    }
+}
+
+#[test]
+fn rotate_clockwise() {
+    assert_eq!(Direction2D::Up.rotate_clockwise(), Direction2D::Right);
+    assert_eq!(
+        Direction2D::UpRight.rotate_clockwise(),
+        Direction2D::DownRight
+    );
+    assert_eq!(
+        Direction2D::DownLeft.rotate_clockwise(),
+        Direction2D::UpLeft
+    );
+    for direction in Direction2D::ALL {
+        let v = direction.to_vector::<i32>();
+        // a clockwise rotation maps (x, y) to (y, -x)
+        assert_eq!(
+            direction.rotate_clockwise().to_vector::<i32>(),
+            Vector::new([v[1], -v[0]])
+        );
+    }
+}
+
+#[test]
+fn rotate_counter_clockwise() {
+    assert_eq!(
+        Direction2D::Up.rotate_counter_clockwise(),
+        Direction2D::Left
+    );
+    assert_eq!(
+        Direction2D::UpLeft.rotate_counter_clockwise(),
+        Direction2D::DownLeft
+    );
+    for direction in Direction2D::ALL {
+        assert_eq!(
+            direction.rotate_clockwise().rotate_counter_clockwise(),
+            direction
+        );
+        assert_eq!(
+            direction
+                .rotate_clockwise_45()
+                .rotate_counter_clockwise_45(),
+            direction
+        );
+    }
+}
+
+#[test]
+fn turns() {
+    assert_eq!(Direction2D::Right.turn_left(), Direction2D::Up);
+    assert_eq!(Direction2D::Right.turn_right(), Direction2D::Down);
+    assert_eq!(Direction2D::DownRight.turn_right(), Direction2D::DownLeft);
+    for direction in Direction2D::ALL {
+        assert_eq!(direction.reverse(), -direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+    }
+}
+
+// Tests for parsing
+
+#[test]
+fn try_from_char() {
+    assert_eq!(Direction2D::try_from('^'), Ok(Direction2D::Up));
+    assert_eq!(Direction2D::try_from('v'), Ok(Direction2D::Down));
+    assert_eq!(Direction2D::try_from('W'), Ok(Direction2D::Left));
+    assert_eq!(Direction2D::try_from('R'), Ok(Direction2D::Right));
+    assert!(Direction2D::try_from('?').is_err());
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("N".parse(), Ok(Direction2D::Up));
+    assert_eq!("ne".parse(), Ok(Direction2D::UpRight));
+    assert_eq!("DR".parse(), Ok(Direction2D::DownRight));
+    assert_eq!("sw".parse(), Ok(Direction2D::DownLeft));
+    assert_eq!("UL".parse(), Ok(Direction2D::UpLeft));
+    assert_eq!("left".parse(), Ok(Direction2D::Left));
+}
+
+#[test]
+fn from_str_invalid() {
+    let error = "UD".parse::<Direction2D>().unwrap_err();
+    assert_eq!(error.input(), "UD");
+}
@@ -0,0 +1,125 @@
+use crate::Vector;
+use crate::d2::core::parse_direction_error::ParseDirectionError;
+use crate::generic::core::vector_coordinate::VectorCoordinate;
+
+const DIMENSIONS: usize = 2;
+
+/// The six directions of a hexagonal grid whose hexagons have a flat top side.
+///
+/// The neighbours of a flat-top hexagon are located to the north, south, and to the
+/// four diagonals (north-east, south-east, south-west and north-west).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatHexDirection2D {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FlatHexDirection2D {
+    /// All the directions, in clockwise order starting from `North`.
+    pub const ALL: [Self; 6] = [
+        FlatHexDirection2D::North,
+        FlatHexDirection2D::NorthEast,
+        FlatHexDirection2D::SouthEast,
+        FlatHexDirection2D::South,
+        FlatHexDirection2D::SouthWest,
+        FlatHexDirection2D::NorthWest,
+    ];
+
+    /// Returns the next direction, turning 60 degrees clockwise.
+    pub fn rotate_clockwise(self) -> Self {
+        match self {
+            FlatHexDirection2D::North => FlatHexDirection2D::NorthEast,
+            FlatHexDirection2D::NorthEast => FlatHexDirection2D::SouthEast,
+            FlatHexDirection2D::SouthEast => FlatHexDirection2D::South,
+            FlatHexDirection2D::South => FlatHexDirection2D::SouthWest,
+            FlatHexDirection2D::SouthWest => FlatHexDirection2D::NorthWest,
+            FlatHexDirection2D::NorthWest => FlatHexDirection2D::North,
+        }
+    }
+
+    /// Returns the previous direction, turning 60 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(self) -> Self {
+        match self {
+            FlatHexDirection2D::North => FlatHexDirection2D::NorthWest,
+            FlatHexDirection2D::NorthWest => FlatHexDirection2D::SouthWest,
+            FlatHexDirection2D::SouthWest => FlatHexDirection2D::South,
+            FlatHexDirection2D::South => FlatHexDirection2D::SouthEast,
+            FlatHexDirection2D::SouthEast => FlatHexDirection2D::NorthEast,
+            FlatHexDirection2D::NorthEast => FlatHexDirection2D::North,
+        }
+    }
+
+    /// Converts the direction into a displacement in axial coordinates `(q, r)`.
+    ///
+    /// See [`HexCoordinate2D`](crate::HexCoordinate2D) for the coordinate convention.
+    pub fn to_vector<T>(&self) -> Vector<T, DIMENSIONS>
+    where
+        T: VectorCoordinate,
+    {
+        match self {
+            FlatHexDirection2D::North => Vector::new([T::zero(), -T::one()]),
+            FlatHexDirection2D::NorthEast => Vector::new([T::one(), -T::one()]),
+            FlatHexDirection2D::SouthEast => Vector::new([T::one(), T::zero()]),
+            FlatHexDirection2D::South => Vector::new([T::zero(), T::one()]),
+            FlatHexDirection2D::SouthWest => Vector::new([-T::one(), T::one()]),
+            FlatHexDirection2D::NorthWest => Vector::new([-T::one(), T::zero()]),
+        }
+    }
+
+    /// Parses a comma-separated list of directions, like `ne,s,sw`.
+    ///
+    /// Whitespace around each direction is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The text to parse
+    ///
+    /// # Returns
+    ///
+    /// The list of directions, or the error of the first direction that cannot be parsed.
+    pub fn parse_path(s: &str) -> Result<Vec<Self>, ParseDirectionError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        s.split(',').map(|item| item.trim().parse()).collect()
+    }
+}
+
+/// Parses a direction from its abbreviation: `n`, `ne`, `se`, `s`, `sw` or `nw`.
+///
+/// The parsing is case-insensitive.
+impl std::str::FromStr for FlatHexDirection2D {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" => Ok(FlatHexDirection2D::North),
+            "ne" => Ok(FlatHexDirection2D::NorthEast),
+            "se" => Ok(FlatHexDirection2D::SouthEast),
+            "s" => Ok(FlatHexDirection2D::South),
+            "sw" => Ok(FlatHexDirection2D::SouthWest),
+            "nw" => Ok(FlatHexDirection2D::NorthWest),
+            _ => Err(ParseDirectionError::new(s)),
+        }
+    }
+}
+
+impl std::ops::Neg for FlatHexDirection2D {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            FlatHexDirection2D::North => FlatHexDirection2D::South,
+            FlatHexDirection2D::NorthEast => FlatHexDirection2D::SouthWest,
+            FlatHexDirection2D::SouthEast => FlatHexDirection2D::NorthWest,
+            FlatHexDirection2D::South => FlatHexDirection2D::North,
+            FlatHexDirection2D::SouthWest => FlatHexDirection2D::NorthEast,
+            FlatHexDirection2D::NorthWest => FlatHexDirection2D::SouthEast,
+        }
+    }
+}
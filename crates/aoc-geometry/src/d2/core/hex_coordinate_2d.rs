@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod hex_coordinate_2d_tests;
+
+use crate::Vector;
+use crate::generic::core::vector_coordinate::VectorCoordinate;
+use num_traits::cast::cast;
+use std::collections::HashSet;
+
+const DIMENSIONS: usize = 2;
+
+/// Displacements to the six neighbours in axial coordinates, in the order used to walk a ring.
+const AXIAL_DIRECTIONS: [[i8; DIMENSIONS]; 6] =
+    [[1, 0], [1, -1], [0, -1], [-1, 0], [-1, 1], [0, 1]];
+
+/// A position in a hexagonal grid.
+///
+/// It uses the [cube coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-cube)
+/// system, where every hexagon is defined by three coordinates `(q, r, s)` that always satisfy
+/// `q + r + s = 0`. Only `q` and `r` (the axial coordinates) are stored, as `s` can be derived.
+///
+/// The same coordinates are valid for flat-top and pointy-top grids; only the meaning of the
+/// directions changes (see [`FlatHexDirection2D`](crate::FlatHexDirection2D) and
+/// [`PointyHexDirection2D`](crate::PointyHexDirection2D)).
+///
+/// # Type Parameters
+///
+/// * `T` - The signed numeric type for coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexCoordinate2D<T: VectorCoordinate> {
+    coordinates: [T; DIMENSIONS],
+}
+
+impl<T: VectorCoordinate> HexCoordinate2D<T> {
+    /// Creates a new hexagon at the origin (all coordinates are zero).
+    pub fn origin() -> Self {
+        Self {
+            coordinates: [T::zero(); DIMENSIONS],
+        }
+    }
+
+    /// Creates a new hexagon from its axial coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The `q` coordinate
+    /// * `r` - The `r` coordinate
+    pub fn new(q: T, r: T) -> Self {
+        Self {
+            coordinates: [q, r],
+        }
+    }
+
+    /// Creates a new hexagon from its cube coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The `q` coordinate
+    /// * `r` - The `r` coordinate
+    /// * `s` - The `s` coordinate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q + r + s` is not zero.
+    pub fn from_cube(q: T, r: T, s: T) -> Self {
+        assert!(
+            q + r + s == T::zero(),
+            "Cube coordinates must satisfy q + r + s = 0."
+        );
+        Self::new(q, r)
+    }
+
+    /// Gets the `q` coordinate.
+    pub fn q(&self) -> T {
+        self.coordinates[0]
+    }
+
+    /// Gets the `r` coordinate.
+    pub fn r(&self) -> T {
+        self.coordinates[1]
+    }
+
+    /// Gets the `s` coordinate, which is derived from the other two.
+    pub fn s(&self) -> T {
+        -self.coordinates[0] - self.coordinates[1]
+    }
+
+    /// Gets the axial coordinates `(q, r)`.
+    pub fn get_axial_coordinates(&self) -> &[T; DIMENSIONS] {
+        &self.coordinates
+    }
+
+    /// Gets the cube coordinates `(q, r, s)`.
+    pub fn get_cube_coordinates(&self) -> [T; 3] {
+        [self.q(), self.r(), self.s()]
+    }
+
+    /// Checks if the hexagon is at the origin (all coordinates are zero).
+    pub fn is_origin(&self) -> bool {
+        self.coordinates.iter().all(|&c| c == T::zero())
+    }
+
+    /// Moves the hexagon by a displacement expressed in axial coordinates.
+    ///
+    /// The direction enums provide these displacements through their `to_vector()` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - The displacement `(q, r)`
+    ///
+    /// # Returns
+    ///
+    /// The new hexagon, or `None` if any coordinate overflows.
+    pub fn move_by<U>(&self, vector: &Vector<U, DIMENSIONS>) -> Option<Self>
+    where
+        U: VectorCoordinate,
+    {
+        let mut coordinates = self.coordinates;
+        for (i, coordinate) in coordinates.iter_mut().enumerate() {
+            *coordinate = coordinate.checked_add(&cast(vector[i])?)?;
+        }
+        Some(Self { coordinates })
+    }
+
+    /// Gets the six adjacent hexagons.
+    ///
+    /// Neighbours whose coordinates would overflow are skipped.
+    ///
+    /// # Returns
+    ///
+    /// A set containing up to 6 neighbouring hexagons.
+    pub fn get_neighbors(&self) -> HashSet<Self> {
+        AXIAL_DIRECTIONS
+            .iter()
+            .filter_map(|&d| self.move_by(&Vector::<i8, DIMENSIONS>::new(d)))
+            .collect()
+    }
+
+    /// Calculates the distance to another hexagon, as the minimum number of steps between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other hexagon
+    ///
+    /// # Returns
+    ///
+    /// The number of steps between both hexagons.
+    pub fn distance_to(&self, other: &Self) -> u64 {
+        self.get_cube_coordinates()
+            .iter()
+            .zip(other.get_cube_coordinates().iter())
+            .map(|(&a, &b)| {
+                cast::<T, i128>(a)
+                    .unwrap()
+                    .abs_diff(cast::<T, i128>(b).unwrap())
+            })
+            .max()
+            .map(|d| cast(d).unwrap())
+            .unwrap()
+    }
+
+    /// Gets all the hexagons that are exactly at the selected distance from this one.
+    ///
+    /// The hexagons are returned in order, walking around the ring.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The distance from this hexagon
+    ///
+    /// # Returns
+    ///
+    /// A list of `6 * radius` hexagons, or only this hexagon if `radius` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any hexagon of the ring is out of bounds.
+    pub fn ring(&self, radius: u64) -> Vec<Self> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let radius_t: T = cast(radius).expect("Radius is out of bounds.");
+        let [start_q, start_r] = AXIAL_DIRECTIONS[4];
+        let start = Vector::<T, DIMENSIONS>::new([
+            cast::<i8, T>(start_q).unwrap() * radius_t,
+            cast::<i8, T>(start_r).unwrap() * radius_t,
+        ]);
+        let mut current = self.move_by(&start).expect("Ring is out of bounds.");
+        let mut result = Vec::with_capacity(6 * radius as usize);
+        for direction in AXIAL_DIRECTIONS {
+            let step = Vector::<i8, DIMENSIONS>::new(direction);
+            for _ in 0..radius {
+                result.push(current);
+                current = current.move_by(&step).expect("Ring is out of bounds.");
+            }
+        }
+        result
+    }
+}
+
+/// Display formatting for hexagons.
+///
+/// Formats the hexagon with its cube coordinates as "(q,r,s)".
+impl<T: VectorCoordinate> std::fmt::Display for HexCoordinate2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{})", self.q(), self.r(), self.s())
+    }
+}
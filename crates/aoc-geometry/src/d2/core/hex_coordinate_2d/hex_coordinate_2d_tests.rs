@@ -0,0 +1,229 @@
+use super::*;
+use crate::{FlatHexDirection2D, PointyHexDirection2D};
+use pretty_assertions::assert_eq;
+
+fn h(q: i32, r: i32) -> HexCoordinate2D<i32> {
+    HexCoordinate2D::<i32>::new(q, r)
+}
+
+// Tests for constructors
+
+#[test]
+fn origin() {
+    let hex = HexCoordinate2D::<i32>::origin();
+    assert!(hex.is_origin());
+    assert_eq!(hex.get_cube_coordinates(), [0, 0, 0]);
+}
+
+#[test]
+fn new_derives_s() {
+    let hex = h(2, -5);
+    assert_eq!(hex.q(), 2);
+    assert_eq!(hex.r(), -5);
+    assert_eq!(hex.s(), 3);
+    assert_eq!(hex.get_axial_coordinates(), &[2, -5]);
+    assert_eq!(hex.get_cube_coordinates(), [2, -5, 3]);
+}
+
+#[test]
+fn from_cube() {
+    assert_eq!(HexCoordinate2D::from_cube(1, 2, -3), h(1, 2));
+}
+
+#[test]
+#[should_panic(expected = "Cube coordinates must satisfy q + r + s = 0.")]
+fn from_cube_invalid_panics() {
+    let _hex = HexCoordinate2D::from_cube(1, 2, 3);
+}
+
+// Tests for move_by
+
+#[test]
+fn move_by_flat_directions_and_back() {
+    for direction in FlatHexDirection2D::ALL {
+        let moved = h(3, -1).move_by(&direction.to_vector::<i32>()).unwrap();
+        assert_eq!(moved.distance_to(&h(3, -1)), 1);
+        let back = moved.move_by(&(-direction).to_vector::<i32>()).unwrap();
+        assert_eq!(back, h(3, -1));
+    }
+}
+
+#[test]
+fn move_by_pointy_directions_and_back() {
+    for direction in PointyHexDirection2D::ALL {
+        let moved = h(-2, 4).move_by(&direction.to_vector::<i32>()).unwrap();
+        assert_eq!(moved.distance_to(&h(-2, 4)), 1);
+        let back = moved.move_by(&(-direction).to_vector::<i32>()).unwrap();
+        assert_eq!(back, h(-2, 4));
+    }
+}
+
+#[test]
+fn move_by_overflow() {
+    let hex = HexCoordinate2D::<i8>::new(i8::MAX, 0);
+    assert_eq!(
+        hex.move_by(&PointyHexDirection2D::East.to_vector::<i8>()),
+        None
+    );
+}
+
+// Tests for get_neighbors
+
+#[test]
+fn get_neighbors() {
+    let neighbors = h(0, 0).get_neighbors();
+    assert_eq!(neighbors.len(), 6);
+    for direction in FlatHexDirection2D::ALL {
+        assert!(neighbors.contains(&h(0, 0).move_by(&direction.to_vector::<i32>()).unwrap()));
+    }
+    for direction in PointyHexDirection2D::ALL {
+        assert!(neighbors.contains(&h(0, 0).move_by(&direction.to_vector::<i32>()).unwrap()));
+    }
+}
+
+// Tests for distance_to
+
+#[test]
+fn distance_to_itself() {
+    assert_eq!(h(4, -7).distance_to(&h(4, -7)), 0);
+}
+
+#[test]
+fn distance_to_other() {
+    assert_eq!(h(0, 0).distance_to(&h(3, -1)), 3);
+    assert_eq!(h(3, -1).distance_to(&h(0, 0)), 3);
+    assert_eq!(h(-2, 5).distance_to(&h(1, -1)), 6);
+}
+
+#[test]
+fn distance_to_extreme_values() {
+    let a = HexCoordinate2D::<i64>::new(i64::MAX / 2, 0);
+    let b = HexCoordinate2D::<i64>::new(-(i64::MAX / 2), 0);
+    assert_eq!(a.distance_to(&b), (i64::MAX - 1) as u64);
+}
+
+// Tests for ring
+
+#[test]
+fn ring_radius_zero() {
+    assert_eq!(h(1, 1).ring(0), vec![h(1, 1)]);
+}
+
+#[test]
+fn ring_radius_one_are_neighbors() {
+    let ring = h(1, 1).ring(1);
+    assert_eq!(ring.len(), 6);
+    assert_eq!(
+        ring.into_iter().collect::<HashSet<_>>(),
+        h(1, 1).get_neighbors()
+    );
+}
+
+#[test]
+fn ring_radius_three() {
+    let center = h(-1, 2);
+    let ring = center.ring(3);
+    assert_eq!(ring.len(), 18);
+    assert!(ring.iter().all(|hex| hex.distance_to(&center) == 3));
+    assert_eq!(ring.iter().collect::<HashSet<_>>().len(), 18);
+    // consecutive hexagons are adjacent, including the last and the first ones
+    for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        assert_eq!(a.distance_to(b), 1);
+    }
+}
+
+// Tests for parsing
+
+#[test]
+fn parse_flat_path() {
+    let path = FlatHexDirection2D::parse_path("ne,s, SW ,n,nw,se").unwrap();
+    assert_eq!(
+        path,
+        vec![
+            FlatHexDirection2D::NorthEast,
+            FlatHexDirection2D::South,
+            FlatHexDirection2D::SouthWest,
+            FlatHexDirection2D::North,
+            FlatHexDirection2D::NorthWest,
+            FlatHexDirection2D::SouthEast,
+        ]
+    );
+}
+
+#[test]
+fn parse_flat_path_empty() {
+    assert_eq!(FlatHexDirection2D::parse_path(""), Ok(vec![]));
+}
+
+#[test]
+fn parse_flat_path_invalid() {
+    let error = FlatHexDirection2D::parse_path("ne,e,s").unwrap_err();
+    assert_eq!(error.input(), "e");
+}
+
+#[test]
+fn parse_pointy_path() {
+    let path = PointyHexDirection2D::parse_path("e,se,sw,w,nw,ne").unwrap();
+    assert_eq!(
+        path,
+        PointyHexDirection2D::ALL[1..]
+            .iter()
+            .copied()
+            .chain([PointyHexDirection2D::NorthEast])
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parse_pointy_path_invalid() {
+    let error = PointyHexDirection2D::parse_path("e,n").unwrap_err();
+    assert_eq!(error.input(), "n");
+}
+
+#[test]
+fn walk_flat_path() {
+    // ne,ne,s,s is 2 steps away
+    let end = FlatHexDirection2D::parse_path("ne,ne,s,s")
+        .unwrap()
+        .iter()
+        .fold(HexCoordinate2D::<i32>::origin(), |hex, d| {
+            hex.move_by(&d.to_vector::<i32>()).unwrap()
+        });
+    assert_eq!(end.distance_to(&HexCoordinate2D::origin()), 2);
+}
+
+// Tests for rotations
+
+#[test]
+fn rotations_are_inverse() {
+    for direction in FlatHexDirection2D::ALL {
+        assert_eq!(
+            direction.rotate_clockwise().rotate_counter_clockwise(),
+            direction
+        );
+    }
+    for direction in PointyHexDirection2D::ALL {
+        assert_eq!(
+            direction.rotate_clockwise().rotate_counter_clockwise(),
+            direction
+        );
+    }
+}
+
+#[test]
+fn three_rotations_is_negation() {
+    for direction in FlatHexDirection2D::ALL {
+        let rotated = direction
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise();
+        assert_eq!(rotated, -direction);
+    }
+}
+
+// Tests for Display
+
+#[test]
+fn display() {
+    assert_eq!(h(1, -3).to_string(), "(1,-3,2)");
+}
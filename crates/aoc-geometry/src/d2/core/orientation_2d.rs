@@ -0,0 +1,33 @@
+/// The rotational direction of an ordered sequence of points in the plane.
+///
+/// It is used to describe the winding of a polygon, or the turn made by three consecutive points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation2D {
+    /// The points turn clockwise.
+    Clockwise,
+    /// The points turn counter-clockwise.
+    CounterClockwise,
+    /// The points are aligned, so there is no turn.
+    Collinear,
+}
+
+impl Orientation2D {
+    /// Gets the orientation associated to the sign of a cross product (or a signed area).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The cross product of two vectors, or a signed area
+    ///
+    /// # Returns
+    ///
+    /// * `CounterClockwise` for positive values
+    /// * `Clockwise` for negative values
+    /// * `Collinear` for zero
+    pub fn from_sign(value: i128) -> Self {
+        match value.signum() {
+            1 => Orientation2D::CounterClockwise,
+            -1 => Orientation2D::Clockwise,
+            _ => Orientation2D::Collinear,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/// Error returned when a direction cannot be parsed from its textual representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    /// The text that could not be parsed.
+    input: String,
+}
+
+impl ParseDirectionError {
+    /// Creates a new error for the specified text.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The text that could not be parsed
+    pub fn new(input: &str) -> Self {
+        Self {
+            input: input.to_string(),
+        }
+    }
+
+    /// Returns the text that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl std::fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction: \"{}\"", self.input)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
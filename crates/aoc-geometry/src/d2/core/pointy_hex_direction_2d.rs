@@ -0,0 +1,125 @@
+use crate::Vector;
+use crate::d2::core::parse_direction_error::ParseDirectionError;
+use crate::generic::core::vector_coordinate::VectorCoordinate;
+
+const DIMENSIONS: usize = 2;
+
+/// The six directions of a hexagonal grid whose hexagons have a pointy top vertex.
+///
+/// The neighbours of a pointy-top hexagon are located to the east, west, and to the
+/// four diagonals (north-east, south-east, south-west and north-west).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexDirection2D {
+    NorthEast,
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl PointyHexDirection2D {
+    /// All the directions, in clockwise order starting from `NorthEast`.
+    pub const ALL: [Self; 6] = [
+        PointyHexDirection2D::NorthEast,
+        PointyHexDirection2D::East,
+        PointyHexDirection2D::SouthEast,
+        PointyHexDirection2D::SouthWest,
+        PointyHexDirection2D::West,
+        PointyHexDirection2D::NorthWest,
+    ];
+
+    /// Returns the next direction, turning 60 degrees clockwise.
+    pub fn rotate_clockwise(self) -> Self {
+        match self {
+            PointyHexDirection2D::NorthEast => PointyHexDirection2D::East,
+            PointyHexDirection2D::East => PointyHexDirection2D::SouthEast,
+            PointyHexDirection2D::SouthEast => PointyHexDirection2D::SouthWest,
+            PointyHexDirection2D::SouthWest => PointyHexDirection2D::West,
+            PointyHexDirection2D::West => PointyHexDirection2D::NorthWest,
+            PointyHexDirection2D::NorthWest => PointyHexDirection2D::NorthEast,
+        }
+    }
+
+    /// Returns the previous direction, turning 60 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(self) -> Self {
+        match self {
+            PointyHexDirection2D::NorthEast => PointyHexDirection2D::NorthWest,
+            PointyHexDirection2D::NorthWest => PointyHexDirection2D::West,
+            PointyHexDirection2D::West => PointyHexDirection2D::SouthWest,
+            PointyHexDirection2D::SouthWest => PointyHexDirection2D::SouthEast,
+            PointyHexDirection2D::SouthEast => PointyHexDirection2D::East,
+            PointyHexDirection2D::East => PointyHexDirection2D::NorthEast,
+        }
+    }
+
+    /// Converts the direction into a displacement in axial coordinates `(q, r)`.
+    ///
+    /// See [`HexCoordinate2D`](crate::HexCoordinate2D) for the coordinate convention.
+    pub fn to_vector<T>(&self) -> Vector<T, DIMENSIONS>
+    where
+        T: VectorCoordinate,
+    {
+        match self {
+            PointyHexDirection2D::NorthEast => Vector::new([T::one(), -T::one()]),
+            PointyHexDirection2D::East => Vector::new([T::one(), T::zero()]),
+            PointyHexDirection2D::SouthEast => Vector::new([T::zero(), T::one()]),
+            PointyHexDirection2D::SouthWest => Vector::new([-T::one(), T::one()]),
+            PointyHexDirection2D::West => Vector::new([-T::one(), T::zero()]),
+            PointyHexDirection2D::NorthWest => Vector::new([T::zero(), -T::one()]),
+        }
+    }
+
+    /// Parses a comma-separated list of directions, like `ne,e,sw`.
+    ///
+    /// Whitespace around each direction is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The text to parse
+    ///
+    /// # Returns
+    ///
+    /// The list of directions, or the error of the first direction that cannot be parsed.
+    pub fn parse_path(s: &str) -> Result<Vec<Self>, ParseDirectionError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        s.split(',').map(|item| item.trim().parse()).collect()
+    }
+}
+
+/// Parses a direction from its abbreviation: `ne`, `e`, `se`, `sw`, `w` or `nw`.
+///
+/// The parsing is case-insensitive.
+impl std::str::FromStr for PointyHexDirection2D {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ne" => Ok(PointyHexDirection2D::NorthEast),
+            "e" => Ok(PointyHexDirection2D::East),
+            "se" => Ok(PointyHexDirection2D::SouthEast),
+            "sw" => Ok(PointyHexDirection2D::SouthWest),
+            "w" => Ok(PointyHexDirection2D::West),
+            "nw" => Ok(PointyHexDirection2D::NorthWest),
+            _ => Err(ParseDirectionError::new(s)),
+        }
+    }
+}
+
+impl std::ops::Neg for PointyHexDirection2D {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            PointyHexDirection2D::NorthEast => PointyHexDirection2D::SouthWest,
+            PointyHexDirection2D::East => PointyHexDirection2D::West,
+            PointyHexDirection2D::SouthEast => PointyHexDirection2D::NorthWest,
+            PointyHexDirection2D::SouthWest => PointyHexDirection2D::NorthEast,
+            PointyHexDirection2D::West => PointyHexDirection2D::East,
+            PointyHexDirection2D::NorthWest => PointyHexDirection2D::SouthEast,
+        }
+    }
+}
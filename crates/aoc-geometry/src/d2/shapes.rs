@@ -1,4 +1,6 @@
 pub mod grid_2d;
 pub mod orthogonal_polygon_2d;
+pub mod polygon_2d;
+pub mod segment_2d;
 pub mod square_diamond_2d;
 pub mod orthogonal_line_2d;
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod orthogonal_polygon_2d_tests;
 
+use crate::CardinalDirection2D;
 use crate::OrthogonalLine2D;
 use crate::Point;
 use crate::Vector;
+use crate::generic::core::point::to_wide;
 use crate::generic::core::point_coordinate::PointCoordinate;
 use crate::generic::core::vector_coordinate::VectorCoordinate;
 use num_traits::cast::cast;
@@ -33,14 +35,57 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
         Self { vertices }
     }
 
+    /// Creates a new orthogonal polygon by following a path, like a turtle, from a starting
+    /// point.
+    ///
+    /// Each step moves the given distance in the given direction, and the position reached
+    /// after each step is a vertex of the polygon. Steps with zero distance are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting point, which is also the first vertex.
+    /// * `steps` - Sequence of directions and distances to follow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any vertex is out of bounds, if the path does not end at the starting point, or
+    /// if the resulting vertices do not form a polygon.
+    pub fn from_steps<I>(start: &Point<T, DIMENSIONS>, steps: I) -> Self
+    where
+        I: IntoIterator<Item = (CardinalDirection2D, u64)>,
+    {
+        let mut vertices = vec![*start];
+        let mut current_point = *start;
+        for (direction, distance) in steps {
+            if distance == 0 {
+                continue;
+            }
+            let unary_vector = direction.to_vector::<i128>();
+            let distance = i128::from(distance);
+            let movement = Vector::<i128, DIMENSIONS>::new([
+                unary_vector[0] * distance,
+                unary_vector[1] * distance,
+            ]);
+            current_point = current_point
+                .move_by(&movement)
+                .expect("Vertex is out of bounds.");
+            vertices.push(current_point);
+        }
+        assert!(
+            vertices.pop() == Some(*start) && !vertices.is_empty(),
+            "The path must end at the starting point to form a closed polygon."
+        );
+        Self::from_vertices(vertices)
+    }
+
     fn are_vertices_orthogonal(vertices: &Vec<Point<T, DIMENSIONS>>) -> bool {
         vertices
             .iter()
             .zip(vertices.iter().cycle().skip(1).take(vertices.len()))
             .all(|(a, b)| {
-                Vector::<i64, DIMENSIONS>::from_points(a, b)
-                    .map(|v| v.is_axis())
-                    .unwrap_or(false)
+                // exactly one coordinate changes along every edge
+                let (a, b) = (to_wide(a), to_wide(b));
+                (a[0] == b[0]) != (a[1] == b[1])
             })
     }
 
@@ -50,7 +95,10 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
     }
 
     /// Calculates the area of this shape.
-    pub fn area(&self) -> f64 {
+    ///
+    /// The area of an orthogonal polygon with integer coordinates is always an integer, so the
+    /// result is exact.
+    pub fn area(&self) -> u128 {
         Self::calculate_arbitrary_polygon_area(&self.vertices)
     }
 
@@ -62,7 +110,11 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
     ///
     /// * `perimeter_points` - List of all the points forming the perimeter of the polygon, ordered
     ///   in a counter-clock-wise or clock-wise sequence.
-    fn calculate_arbitrary_polygon_area(perimeter_points: &Vec<Point<T, DIMENSIONS>>) -> f64 {
+    ///
+    /// # Returns
+    ///
+    /// The area, rounded down if it is not an integer. It is always exact for orthogonal polygons.
+    fn calculate_arbitrary_polygon_area(perimeter_points: &Vec<Point<T, DIMENSIONS>>) -> u128 {
         let (left_sum, right_sum) = perimeter_points
             .iter()
             .zip(
@@ -79,7 +131,7 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
                 )
             });
 
-        cast::<i128, f64>(left_sum - right_sum).unwrap().abs() * 0.5
+        (left_sum - right_sum).unsigned_abs() / 2
     }
 
     /// Determines whether the specified point is outside the shape.
@@ -156,7 +208,9 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
     }
 
     /// Calculates the perimeter length of this shape.
-    pub fn perimeter(&self) -> u64 {
+    ///
+    /// The edges are measured with `i128` coordinates, so the result is exact for any type `T`.
+    pub fn perimeter(&self) -> u128 {
         self.vertices
             .iter()
             .zip(
@@ -167,9 +221,8 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
                     .take(self.vertices.len()),
             )
             .map(|(a, b)| {
-                Vector::<i64, DIMENSIONS>::from_points(b, a)
-                    .unwrap()
-                    .manhattan_distance()
+                let (a, b) = (to_wide(a), to_wide(b));
+                a[0].abs_diff(b[0]) + a[1].abs_diff(b[1])
             })
             .sum()
     }
@@ -182,15 +235,27 @@ impl<T: PointCoordinate> OrthogonalPolygon2D<T> {
     ///
     /// # Returns
     ///
-    /// The number of intrinsic points inside the shape.
-    pub fn number_of_intrinsic_points(&self) -> u64 {
-        let boundary_points = self.get_boundary_points();
-        let area = Self::calculate_arbitrary_polygon_area(&boundary_points);
-        // Using Pick's Theorem: A = I + B/2 - 1  =>  I = A - B/2 + 1
-        (area - (boundary_points.len() as f64 * 0.5) + 1.0) as u64
+    /// The number of intrinsic points inside the shape, which is 0 if the shape has no area.
+    pub fn number_of_intrinsic_points(&self) -> u128 {
+        // In an orthogonal polygon, the number of integer points on the boundary is the perimeter.
+        let boundary_points = self.perimeter();
+        // Using Pick's Theorem: A = I + B/2 - 1  =>  2I = 2A - B + 2
+        // A path that doubles back has no interior, and its perimeter goes over the same points
+        // twice, so the formula does not apply.
+        (2 * self.area() + 2).saturating_sub(boundary_points) / 2
+    }
+
+    /// Calculates the number of integer points covered by this shape, both inside the shape
+    /// and on its perimeter.
+    ///
+    /// If every integer point represents a grid cell, it is the number of cells enclosed by the
+    /// perimeter, including the cells of the perimeter itself.
+    pub fn number_of_covered_points(&self) -> u128 {
+        self.number_of_intrinsic_points() + self.perimeter()
     }
 
-    fn get_boundary_points(&self) -> Vec<Point<T, DIMENSIONS>> {
+    /// Returns all the integer points located on the perimeter of this shape, in order.
+    pub fn get_boundary_points(&self) -> Vec<Point<T, DIMENSIONS>> {
         self.vertices
             .iter()
             .zip(
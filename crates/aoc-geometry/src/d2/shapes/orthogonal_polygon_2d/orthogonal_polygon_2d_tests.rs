@@ -63,6 +63,37 @@ fn perimeter() {
     assert_eq!(polygon.perimeter(), 38);
 }
 
+#[test]
+fn perimeter_huge_polygon() {
+    let vertices = vec![
+        Point::<i128, 2>::new([-i128::from(u64::MAX), 0]),
+        Point::<i128, 2>::new([i128::from(u64::MAX), 0]),
+        Point::<i128, 2>::new([i128::from(u64::MAX), 1]),
+        Point::<i128, 2>::new([-i128::from(u64::MAX), 1]),
+    ];
+    let polygon = OrthogonalPolygon2D::from_vertices(vertices);
+    assert_eq!(polygon.perimeter(), 4 * u128::from(u64::MAX) + 2);
+    assert_eq!(polygon.number_of_intrinsic_points(), 0);
+    assert_eq!(
+        polygon.number_of_covered_points(),
+        4 * u128::from(u64::MAX) + 2
+    );
+}
+
+// Tests for number_of_covered_points
+
+#[test]
+fn number_of_covered_points() {
+    let vertices = vec![
+        Point::<i32, 2>::new([1, 0]),
+        Point::<i32, 2>::new([3, 0]),
+        Point::<i32, 2>::new([3, 2]),
+        Point::<i32, 2>::new([1, 2]),
+    ];
+    let polygon = OrthogonalPolygon2D::from_vertices(vertices);
+    assert_eq!(polygon.number_of_covered_points(), 9);
+}
+
 // Tests for calculate_boundary_points
 
 #[test]
@@ -109,7 +140,86 @@ fn area() {
         Point::<i32, 2>::new([0, -2]),
     ];
     let polygon = OrthogonalPolygon2D::from_vertices(vertices);
-    assert_approx_eq!(polygon.area(), 42.0);
+    assert_eq!(polygon.area(), 42);
+}
+
+#[test]
+fn area_huge_polygon() {
+    let vertices = vec![
+        Point::<i64, 2>::new([0, 0]),
+        Point::<i64, 2>::new([4_000_000_000_000_001, 0]),
+        Point::<i64, 2>::new([4_000_000_000_000_001, 3]),
+        Point::<i64, 2>::new([0, 3]),
+    ];
+    let polygon = OrthogonalPolygon2D::from_vertices(vertices);
+    assert_eq!(polygon.area(), 12_000_000_000_000_003);
+}
+
+// Tests for from_steps
+
+#[test]
+fn from_steps() {
+    let steps = [
+        (CardinalDirection2D::Right, 6),
+        (CardinalDirection2D::Down, 5),
+        (CardinalDirection2D::Left, 2),
+        (CardinalDirection2D::Down, 2),
+        (CardinalDirection2D::Right, 2),
+        (CardinalDirection2D::Down, 2),
+        (CardinalDirection2D::Left, 5),
+        (CardinalDirection2D::Up, 2),
+        (CardinalDirection2D::Left, 1),
+        (CardinalDirection2D::Up, 2),
+        (CardinalDirection2D::Right, 2),
+        (CardinalDirection2D::Up, 3),
+        (CardinalDirection2D::Left, 2),
+        (CardinalDirection2D::Up, 2),
+    ];
+    let polygon = OrthogonalPolygon2D::from_steps(&Point::<i32, 2>::origin(), steps);
+    assert_eq!(polygon.get_vertexes().len(), 14);
+    assert_eq!(polygon.get_vertexes()[0], Point::new([0, 0]));
+    assert_eq!(polygon.get_vertexes()[1], Point::new([6, 0]));
+    assert_eq!(polygon.get_vertexes()[13], Point::new([0, -2]));
+    assert_eq!(polygon.area(), 42);
+    assert_eq!(polygon.perimeter(), 38);
+    assert_eq!(polygon.number_of_covered_points(), 62);
+}
+
+#[test]
+fn from_steps_ignores_empty_steps() {
+    let steps = [
+        (CardinalDirection2D::Up, 2),
+        (CardinalDirection2D::Left, 0),
+        (CardinalDirection2D::Right, 2),
+        (CardinalDirection2D::Down, 2),
+        (CardinalDirection2D::Left, 2),
+    ];
+    let polygon = OrthogonalPolygon2D::from_steps(&Point::<i64, 2>::new([5, 5]), steps);
+    assert_eq!(polygon.get_vertexes().len(), 4);
+    assert_eq!(polygon.number_of_covered_points(), 9);
+}
+
+#[test]
+fn from_steps_doubling_back() {
+    let steps = [
+        (CardinalDirection2D::Right, 5),
+        (CardinalDirection2D::Left, 3),
+        (CardinalDirection2D::Left, 2),
+    ];
+    let polygon = OrthogonalPolygon2D::from_steps(&Point::<i32, 2>::origin(), steps);
+    assert_eq!(polygon.area(), 0);
+    assert_eq!(polygon.perimeter(), 10);
+    assert_eq!(polygon.number_of_intrinsic_points(), 0);
+}
+
+#[test]
+#[should_panic(expected = "The path must end at the starting point to form a closed polygon.")]
+fn from_steps_open_path_panics() {
+    let steps = [
+        (CardinalDirection2D::Up, 2),
+        (CardinalDirection2D::Right, 2),
+    ];
+    let _polygon = OrthogonalPolygon2D::from_steps(&Point::<i32, 2>::origin(), steps);
 }
 
 // Tests for calculate_angle_between_vectors
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod polygon_2d_tests;
+
+use crate::Orientation2D;
+use crate::Point;
+use crate::PositionStatus;
+use crate::d2::shapes::segment_2d::{cross_product, is_on_segment};
+use crate::generic::core::point::to_wide;
+use crate::generic::core::point_coordinate::PointCoordinate;
+use num_integer::Integer;
+
+const DIMENSIONS: usize = 2;
+
+/// A simple polygon whose vertices have integer coordinates, with edges in any direction.
+///
+/// All the calculations are done with exact integer arithmetic, so they are valid for huge
+/// polygons too.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of coordinate values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon2D<T: PointCoordinate> {
+    vertices: Vec<Point<T, DIMENSIONS>>,
+}
+
+impl<T: PointCoordinate> Polygon2D<T> {
+    /// Creates a new polygon from a list of vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - List of vertexes of the shape, in sequential order following the perimeter
+    ///   (clockwise or counterclockwise).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than 3 vertices.
+    pub fn from_vertices(vertices: Vec<Point<T, DIMENSIONS>>) -> Self {
+        assert!(
+            vertices.len() >= 3,
+            "A polygon must have at least 3 vertices."
+        );
+        Self { vertices }
+    }
+
+    /// Returns all the vertexes of this shape.
+    pub fn get_vertexes(&self) -> &Vec<Point<T, DIMENSIONS>> {
+        &self.vertices
+    }
+
+    /// Returns an iterator over the edges of this shape, as pairs of consecutive vertices
+    /// converted to `i128`. The last edge connects the last vertex with the first one.
+    fn edges(&self) -> impl Iterator<Item = ([i128; DIMENSIONS], [i128; DIMENSIONS])> + '_ {
+        self.vertices
+            .iter()
+            .zip(
+                self.vertices
+                    .iter()
+                    .cycle()
+                    .skip(1)
+                    .take(self.vertices.len()),
+            )
+            .map(|(a, b)| (to_wide(a), to_wide(b)))
+    }
+
+    /// Calculates twice the signed area of this shape with the
+    /// [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula).
+    ///
+    /// # Returns
+    ///
+    /// A positive value if the vertices are in counter-clockwise order, or a negative value if
+    /// they are in clockwise order.
+    pub fn signed_double_area(&self) -> i128 {
+        self.edges().map(|(a, b)| a[0] * b[1] - b[0] * a[1]).sum()
+    }
+
+    /// Calculates twice the area of this shape.
+    ///
+    /// The area of a polygon with integer coordinates is always a multiple of `0.5`, so its
+    /// double is always an exact integer.
+    pub fn double_area(&self) -> u128 {
+        self.signed_double_area().unsigned_abs()
+    }
+
+    /// Calculates the area of this shape, rounded down to the closest integer.
+    ///
+    /// Use [`Self::double_area`] if the half unit matters.
+    pub fn area(&self) -> u128 {
+        self.double_area() / 2
+    }
+
+    /// Determines the order in which the vertices are listed.
+    ///
+    /// # Returns
+    ///
+    /// The orientation of the vertices, or `Collinear` if the shape has no area.
+    pub fn orientation(&self) -> Orientation2D {
+        Orientation2D::from_sign(self.signed_double_area())
+    }
+
+    /// Calculates the number of integer points located on the perimeter of this shape.
+    ///
+    /// Each edge from `a` to `b` contains `gcd(|b.x - a.x|, |b.y - a.y|)` integer points, if
+    /// one of its vertices is excluded.
+    pub fn number_of_boundary_points(&self) -> u128 {
+        self.edges()
+            .map(|(a, b)| (b[0] - a[0]).gcd(&(b[1] - a[1])).unsigned_abs())
+            .sum()
+    }
+
+    /// Calculates the number of intrinsic points inside this shape.
+    ///
+    /// Applies [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem), a formula for the
+    /// area of a simple polygon with integer vertex coordinates, to find the number of integer
+    /// points interior to the polygon.
+    ///
+    /// # Returns
+    ///
+    /// The number of intrinsic points inside the shape, which is 0 if the shape has no area.
+    pub fn number_of_intrinsic_points(&self) -> u128 {
+        // Using Pick's Theorem: A = I + B/2 - 1  =>  2I = 2A - B + 2
+        // A degenerate shape has no interior, and its perimeter goes back and forth over the same
+        // points, so the formula does not apply.
+        (self.double_area() + 2).saturating_sub(self.number_of_boundary_points()) / 2
+    }
+
+    /// Calculates the number of integer points covered by this shape, both inside the shape
+    /// and on its perimeter.
+    pub fn number_of_covered_points(&self) -> u128 {
+        self.number_of_intrinsic_points() + self.number_of_boundary_points()
+    }
+
+    /// Determines whether the specified point is on the border the shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the point is on the border of the shape, `false` otherwise.
+    pub fn is_on_edge(&self, point: &Point<T, DIMENSIONS>) -> bool {
+        let p = to_wide(point);
+        self.edges().any(|(a, b)| is_on_segment(&a, &b, &p))
+    }
+
+    /// Determines whether the specified point is strictly inside the shape using
+    /// [Winding Number algorithm](https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm).
+    ///
+    /// WARNING: Points on the edge are not considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the point is inside the shape, `false` otherwise.
+    pub fn is_inside(&self, point: &Point<T, DIMENSIONS>) -> bool {
+        !self.is_on_edge(point) && self.winding_number(point) != 0
+    }
+
+    /// Determines whether the specified point is outside the shape.
+    ///
+    /// WARNING: Points on the edge are not considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the point is outside the shape, `false` otherwise.
+    pub fn is_outside(&self, point: &Point<T, DIMENSIONS>) -> bool {
+        !self.is_on_edge(point) && self.winding_number(point) == 0
+    }
+
+    /// Determines the position of the specified point relative to this shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Returns
+    ///
+    /// Whether the point is inside, outside or on the border of the shape.
+    pub fn check_position(&self, point: &Point<T, DIMENSIONS>) -> PositionStatus {
+        if self.is_on_edge(point) {
+            PositionStatus::OnBorder
+        } else if self.winding_number(point) != 0 {
+            PositionStatus::Inside
+        } else {
+            PositionStatus::Outside
+        }
+    }
+
+    /// Calculates how many times the perimeter of the shape winds around the point.
+    fn winding_number(&self, point: &Point<T, DIMENSIONS>) -> i64 {
+        let p = to_wide(point);
+        self.edges()
+            .map(|(a, b)| {
+                if a[1] <= p[1] {
+                    if b[1] > p[1] && cross_product(&a, &b, &p) > 0 {
+                        return 1;
+                    }
+                } else if b[1] <= p[1] && cross_product(&a, &b, &p) < 0 {
+                    return -1;
+                }
+                0
+            })
+            .sum()
+    }
+}
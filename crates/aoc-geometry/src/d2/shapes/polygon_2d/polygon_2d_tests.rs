@@ -0,0 +1,181 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn p(x: i32, y: i32) -> Point<i32, DIMENSIONS> {
+    Point::<i32, DIMENSIONS>::new([x, y])
+}
+
+fn triangle() -> Polygon2D<i32> {
+    Polygon2D::from_vertices(vec![p(0, 0), p(4, 0), p(0, 3)])
+}
+
+fn diamond() -> Polygon2D<i32> {
+    Polygon2D::from_vertices(vec![p(0, 2), p(2, 0), p(0, -2), p(-2, 0)])
+}
+
+fn orthogonal_shape() -> Polygon2D<i32> {
+    Polygon2D::from_vertices(vec![
+        p(0, 0),
+        p(6, 0),
+        p(6, -5),
+        p(4, -5),
+        p(4, -7),
+        p(6, -7),
+        p(6, -9),
+        p(1, -9),
+        p(1, -7),
+        p(0, -7),
+        p(0, -5),
+        p(2, -5),
+        p(2, -2),
+        p(0, -2),
+    ])
+}
+
+// Tests for from_vertices
+
+#[test]
+#[should_panic(expected = "A polygon must have at least 3 vertices.")]
+fn from_vertices_too_few_panics() {
+    let _polygon = Polygon2D::from_vertices(vec![p(0, 0), p(1, 1)]);
+}
+
+// Tests for area
+
+#[test]
+fn area_triangle() {
+    let polygon = triangle();
+    assert_eq!(polygon.signed_double_area(), 12);
+    assert_eq!(polygon.double_area(), 12);
+    assert_eq!(polygon.area(), 6);
+}
+
+#[test]
+fn area_half_unit() {
+    let polygon = Polygon2D::from_vertices(vec![p(0, 0), p(1, 0), p(0, 1)]);
+    assert_eq!(polygon.double_area(), 1);
+    assert_eq!(polygon.area(), 0);
+}
+
+#[test]
+fn area_diamond() {
+    assert_eq!(diamond().double_area(), 16);
+    assert_eq!(diamond().area(), 8);
+}
+
+#[test]
+fn area_orthogonal_shape() {
+    assert_eq!(orthogonal_shape().area(), 42);
+}
+
+#[test]
+fn area_huge_coordinates() {
+    let m = i64::MAX;
+    let polygon = Polygon2D::from_vertices(vec![
+        Point::<i64, 2>::new([0, 0]),
+        Point::<i64, 2>::new([m, 0]),
+        Point::<i64, 2>::new([m, m]),
+        Point::<i64, 2>::new([0, m]),
+    ]);
+    assert_eq!(polygon.area(), (m as u128) * (m as u128));
+}
+
+// Tests for orientation
+
+#[test]
+fn orientation() {
+    assert_eq!(triangle().orientation(), Orientation2D::CounterClockwise);
+    assert_eq!(diamond().orientation(), Orientation2D::Clockwise);
+    let degenerate = Polygon2D::from_vertices(vec![p(0, 0), p(1, 1), p(2, 2)]);
+    assert_eq!(degenerate.orientation(), Orientation2D::Collinear);
+}
+
+// Tests for number_of_boundary_points
+
+#[test]
+fn number_of_boundary_points() {
+    assert_eq!(triangle().number_of_boundary_points(), 8);
+    assert_eq!(diamond().number_of_boundary_points(), 8);
+    assert_eq!(orthogonal_shape().number_of_boundary_points(), 38);
+}
+
+// Tests for number_of_intrinsic_points
+
+#[test]
+fn number_of_intrinsic_points_triangle() {
+    assert_eq!(triangle().number_of_intrinsic_points(), 3);
+    assert_eq!(triangle().number_of_covered_points(), 11);
+}
+
+#[test]
+fn number_of_intrinsic_points_diamond() {
+    assert_eq!(diamond().number_of_intrinsic_points(), 5);
+    assert_eq!(diamond().number_of_covered_points(), 13);
+}
+
+#[test]
+fn number_of_intrinsic_points_orthogonal_shape() {
+    assert_eq!(orthogonal_shape().number_of_intrinsic_points(), 24);
+}
+
+#[test]
+fn number_of_intrinsic_points_degenerate() {
+    let polygon = Polygon2D::from_vertices(vec![p(0, 0), p(2, 0), p(4, 0)]);
+    assert_eq!(polygon.double_area(), 0);
+    assert_eq!(polygon.number_of_intrinsic_points(), 0);
+}
+
+#[test]
+fn number_of_intrinsic_points_matches_brute_force() {
+    let polygon = Polygon2D::from_vertices(vec![p(-3, -1), p(5, -4), p(7, 6), p(1, 3), p(-2, 8)]);
+    let mut inside = 0;
+    let mut border = 0;
+    for x in -10..=10 {
+        for y in -10..=10 {
+            match polygon.check_position(&p(x, y)) {
+                PositionStatus::Inside => inside += 1,
+                PositionStatus::OnBorder => border += 1,
+                PositionStatus::Outside => {}
+            }
+        }
+    }
+    assert_eq!(polygon.number_of_intrinsic_points(), inside);
+    assert_eq!(polygon.number_of_boundary_points(), border);
+}
+
+// Tests for is_outside, is_inside, is_on_edge and check_position
+
+#[test]
+fn check_position_triangle() {
+    let polygon = triangle();
+    assert_eq!(polygon.check_position(&p(1, 1)), PositionStatus::Inside);
+    assert_eq!(polygon.check_position(&p(4, 0)), PositionStatus::OnBorder);
+    assert_eq!(polygon.check_position(&p(2, 0)), PositionStatus::OnBorder);
+    assert_eq!(polygon.check_position(&p(0, 2)), PositionStatus::OnBorder);
+    assert_eq!(polygon.check_position(&p(3, 1)), PositionStatus::Outside);
+    assert_eq!(polygon.check_position(&p(-1, 0)), PositionStatus::Outside);
+    assert_eq!(polygon.check_position(&p(8, -3)), PositionStatus::Outside);
+}
+
+#[test]
+fn is_inside_concave() {
+    let polygon = orthogonal_shape();
+    assert!(polygon.is_inside(&p(1, -1)));
+    assert!(polygon.is_inside(&p(3, -6)));
+    assert!(polygon.is_outside(&p(5, -6)));
+    assert!(!polygon.is_inside(&p(1, -3)));
+    assert!(polygon.is_outside(&p(1, -3)));
+    assert!(polygon.is_on_edge(&p(2, -3)));
+    assert!(!polygon.is_outside(&p(2, -3)));
+    assert!(!polygon.is_inside(&p(2, -3)));
+}
+
+#[test]
+fn is_inside_does_not_depend_on_orientation() {
+    let mut vertices = diamond().get_vertexes().clone();
+    vertices.reverse();
+    let reversed = Polygon2D::from_vertices(vertices);
+    assert!(reversed.is_inside(&p(1, 0)));
+    assert!(reversed.is_on_edge(&p(1, 1)));
+    assert!(reversed.is_outside(&p(2, 1)));
+}
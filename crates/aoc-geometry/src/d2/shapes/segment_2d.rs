@@ -0,0 +1,342 @@
+#[cfg(test)]
+mod segment_2d_tests;
+
+use crate::Orientation2D;
+use crate::Point;
+use crate::generic::core::point::to_wide;
+use crate::generic::core::point_coordinate::PointCoordinate;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::cast::cast;
+
+const DIMENSIONS: usize = 2;
+const NUM_OF_VERTEXES_IN_LINE: usize = 2;
+
+/// The common part of two segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentIntersection2D<T: PointCoordinate> {
+    /// The segments cross (or touch) at a single point, which may have non-integer coordinates.
+    Point([Ratio<i128>; DIMENSIONS]),
+    /// The segments are collinear and share a stretch of more than one point.
+    Segment(Segment2D<T>),
+}
+
+impl<T: PointCoordinate> SegmentIntersection2D<T> {
+    /// Gets the intersection point if it has integer coordinates.
+    ///
+    /// # Returns
+    ///
+    /// The intersection point, or `None` if the intersection is a segment, or the point has
+    /// non-integer coordinates.
+    pub fn to_lattice_point(&self) -> Option<Point<T, DIMENSIONS>> {
+        match self {
+            SegmentIntersection2D::Point([x, y]) if x.is_integer() && y.is_integer() => {
+                Some(Point::new([cast(x.to_integer())?, cast(y.to_integer())?]))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A line segment between two points of the plane, with any slope.
+///
+/// Unlike [`OrthogonalLine2D`](crate::OrthogonalLine2D), which only supports axis-aligned and
+/// 45-degree segments, this type supports arbitrary slopes. All the calculations are done with
+/// exact integer or rational arithmetic.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of coordinate values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment2D<T: PointCoordinate> {
+    /// The two points that define the line segment.
+    vertices: [Point<T, DIMENSIONS>; NUM_OF_VERTEXES_IN_LINE],
+}
+
+impl<T: PointCoordinate> Segment2D<T> {
+    /// Creates a new segment from two distinct points.
+    ///
+    /// The segment will be directed from the first point to the second point.
+    ///
+    /// # Arguments
+    ///
+    /// * `p1` - The first point (start of the segment)
+    /// * `p2` - The second point (end of the segment)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two points are identical, as they cannot form a segment
+    pub fn from_points(p1: &Point<T, DIMENSIONS>, p2: &Point<T, DIMENSIONS>) -> Self {
+        assert!(p1 != p2, "Points must be distinct to form a segment.");
+        Self {
+            vertices: [*p1, *p2],
+        }
+    }
+
+    /// Returns the two points that define the segment.
+    pub fn get_vertexes(&self) -> &[Point<T, DIMENSIONS>; NUM_OF_VERTEXES_IN_LINE] {
+        &self.vertices
+    }
+
+    /// Returns the vector from the first point to the second point.
+    fn inherent_vector(&self) -> [i128; DIMENSIONS] {
+        let [a, b] = self.vertices.map(|p| to_wide(&p));
+        [b[0] - a[0], b[1] - a[1]]
+    }
+
+    /// Checks if the segment is parallel to one of the axes.
+    pub fn is_axis(&self) -> bool {
+        self.inherent_vector().contains(&0)
+    }
+
+    /// Checks if the segment has a slope of 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        let [dx, dy] = self.inherent_vector();
+        dx.abs() == dy.abs()
+    }
+
+    /// Calculates the number of points with integer coordinates located on the segment,
+    /// including both ends.
+    pub fn number_of_lattice_points(&self) -> u128 {
+        let [dx, dy] = self.inherent_vector();
+        dx.gcd(&dy).unsigned_abs() + 1
+    }
+
+    /// Determines on which side of this segment (extended as an infinite line) a point is.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    ///
+    /// # Returns
+    ///
+    /// `CounterClockwise` if the point is to the left of the segment, `Clockwise` if it is to
+    /// the right, or `Collinear` if it is on the infinite line.
+    pub fn side_of(&self, point: &Point<T, DIMENSIONS>) -> Orientation2D {
+        let [a, b] = self.vertices.map(|p| to_wide(&p));
+        Orientation2D::from_sign(cross_product(&a, &b, &to_wide(point)))
+    }
+
+    /// Checks if a point is located on the segment, both ends included.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to check.
+    pub fn contains_point(&self, point: &Point<T, DIMENSIONS>) -> bool {
+        let [a, b] = self.vertices.map(|p| to_wide(&p));
+        is_on_segment(&a, &b, &to_wide(point))
+    }
+
+    /// Checks if this segment is parallel to another one (collinear segments included).
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        let r = self.inherent_vector();
+        let s = other.inherent_vector();
+        r[0] * s[1] - r[1] * s[0] == 0
+    }
+
+    /// Checks if both segments lie on the same infinite line.
+    pub fn is_collinear(&self, other: &Self) -> bool {
+        self.is_parallel(other)
+            && other
+                .vertices
+                .iter()
+                .all(|p| self.side_of(p) == Orientation2D::Collinear)
+    }
+
+    /// Checks if this segment shares at least one point with another segment.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// Calculates the common part of two segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other segment
+    ///
+    /// # Returns
+    ///
+    /// `None` if the segments do not touch. Otherwise, the exact intersection point, or the
+    /// shared segment if both segments are collinear and overlap in more than one point.
+    pub fn intersect(&self, other: &Self) -> Option<SegmentIntersection2D<T>> {
+        let p = to_wide(&self.vertices[0]);
+        let q = to_wide(&other.vertices[0]);
+        let r = self.inherent_vector();
+        let s = other.inherent_vector();
+        let qp = [q[0] - p[0], q[1] - p[1]];
+        let denominator = r[0] * s[1] - r[1] * s[0];
+
+        if denominator == 0 {
+            if qp[0] * r[1] - qp[1] * r[0] != 0 {
+                // parallel, but not on the same line
+                return None;
+            }
+            return self.collinear_intersection(other);
+        }
+
+        let t = Ratio::new(qp[0] * s[1] - qp[1] * s[0], denominator);
+        let u = Ratio::new(qp[0] * r[1] - qp[1] * r[0], denominator);
+        let range = Ratio::from_integer(0)..=Ratio::from_integer(1);
+        if !range.contains(&t) || !range.contains(&u) {
+            return None;
+        }
+        Some(SegmentIntersection2D::Point([
+            Ratio::from_integer(p[0]) + t * r[0],
+            Ratio::from_integer(p[1]) + t * r[1],
+        ]))
+    }
+
+    /// Calculates the common part of two collinear segments.
+    fn collinear_intersection(&self, other: &Self) -> Option<SegmentIntersection2D<T>> {
+        let origin = to_wide(&self.vertices[0]);
+        let r = self.inherent_vector();
+        // the ends of the common part are always ends of the original segments
+        let projection = |point: &Point<T, DIMENSIONS>| {
+            let p = to_wide(point);
+            (p[0] - origin[0]) * r[0] + (p[1] - origin[1]) * r[1]
+        };
+        let common_points: Vec<_> = self
+            .vertices
+            .iter()
+            .chain(other.vertices.iter())
+            .filter(|p| self.contains_point(p) && other.contains_point(p))
+            .collect();
+        let start = common_points.iter().min_by_key(|p| projection(p))?;
+        let end = common_points.iter().max_by_key(|p| projection(p))?;
+        if start == end {
+            let [x, y] = to_wide(start);
+            Some(SegmentIntersection2D::Point([
+                Ratio::from_integer(x),
+                Ratio::from_integer(y),
+            ]))
+        } else {
+            Some(SegmentIntersection2D::Segment(Segment2D::from_points(
+                start, end,
+            )))
+        }
+    }
+
+    /// Creates an iterator that yields all the points with integer coordinates located on the
+    /// segment, from start to end.
+    ///
+    /// The step between points is the inherent vector of the segment divided by the greatest
+    /// common divisor of its coordinates, so every yielded point is exactly on the segment.
+    pub fn iter(&self) -> Segment2DIterator<T> {
+        let [dx, dy] = self.inherent_vector();
+        let divisor = dx.gcd(&dy);
+        Segment2DIterator {
+            current: to_wide(&self.vertices[0]),
+            step: [dx / divisor, dy / divisor],
+            remaining: divisor.unsigned_abs() + 1,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Gets the points of the grid that best approximate the segment, from start to end, using
+    /// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm).
+    ///
+    /// Consecutive points are always adjacent (orthogonally or diagonally).
+    pub fn rasterize(&self) -> impl Iterator<Item = Point<T, DIMENSIONS>> {
+        let [mut x, mut y] = to_wide(&self.vertices[0]);
+        let [x1, y1] = to_wide(&self.vertices[1]);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = (x1 - x).signum();
+        let sy = (y1 - y).signum();
+        let mut error = dx + dy;
+        let mut points = Vec::with_capacity((dx.max(-dy) + 1) as usize);
+        loop {
+            points.push(Point::new([cast(x).unwrap(), cast(y).unwrap()]));
+            if x == x1 && y == y1 {
+                break;
+            }
+            let double_error = 2 * error;
+            if double_error >= dy {
+                error += dy;
+                x += sx;
+            }
+            if double_error <= dx {
+                error += dx;
+                y += sy;
+            }
+        }
+        points.into_iter()
+    }
+}
+
+/// Iterator for the integer points along a segment.
+pub struct Segment2DIterator<T: PointCoordinate> {
+    current: [i128; DIMENSIONS],
+    step: [i128; DIMENSIONS],
+    remaining: u128,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: PointCoordinate> Iterator for Segment2DIterator<T> {
+    type Item = Point<T, DIMENSIONS>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let point = Point::new(self.current.map(|c| cast(c).unwrap()));
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.current = [
+                self.current[0] + self.step[0],
+                self.current[1] + self.step[1],
+            ];
+        }
+        Some(point)
+    }
+}
+
+impl<T: PointCoordinate> IntoIterator for Segment2D<T> {
+    type Item = Point<T, DIMENSIONS>;
+    type IntoIter = Segment2DIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PointCoordinate> IntoIterator for &Segment2D<T> {
+    type Item = Point<T, DIMENSIONS>;
+    type IntoIter = Segment2DIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Display formatting for segments.
+///
+/// Formats the segment as "[(x,y),(x,y)]".
+impl<T: PointCoordinate> std::fmt::Display for Segment2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points = self.vertices.map(|c| c.to_string());
+        write!(f, "[{}]", points.join(","))
+    }
+}
+
+/// Calculates the cross product of the vectors `a -> b` and `a -> p`.
+///
+/// It is positive if `p` is to the left of the line `a -> b`, negative if it is to the right,
+/// and zero if the three points are collinear.
+pub(crate) fn cross_product(
+    a: &[i128; DIMENSIONS],
+    b: &[i128; DIMENSIONS],
+    p: &[i128; DIMENSIONS],
+) -> i128 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (p[0] - a[0]) * (b[1] - a[1])
+}
+
+/// Checks if the point `p` is located on the segment between `a` and `b`, both included.
+pub(crate) fn is_on_segment(
+    a: &[i128; DIMENSIONS],
+    b: &[i128; DIMENSIONS],
+    p: &[i128; DIMENSIONS],
+) -> bool {
+    cross_product(a, b, p) == 0
+        && (0..DIMENSIONS).all(|i| a[i].min(b[i]) <= p[i] && p[i] <= a[i].max(b[i]))
+}
@@ -0,0 +1,154 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn p(x: i32, y: i32) -> Point<i32, DIMENSIONS> {
+    Point::<i32, DIMENSIONS>::new([x, y])
+}
+
+fn s(x1: i32, y1: i32, x2: i32, y2: i32) -> Segment2D<i32> {
+    Segment2D::from_points(&p(x1, y1), &p(x2, y2))
+}
+
+fn r(numerator: i128, denominator: i128) -> Ratio<i128> {
+    Ratio::new(numerator, denominator)
+}
+
+// Tests for from_points
+
+#[test]
+#[should_panic(expected = "Points must be distinct to form a segment.")]
+fn from_points_same_point_panics() {
+    let _segment = s(1, 1, 1, 1);
+}
+
+#[test]
+fn classification() {
+    assert!(s(0, 0, 0, 5).is_axis());
+    assert!(!s(0, 0, 0, 5).is_diagonal());
+    assert!(s(0, 0, -3, 3).is_diagonal());
+    assert!(!s(0, 0, 2, 5).is_axis());
+    assert!(!s(0, 0, 2, 5).is_diagonal());
+}
+
+#[test]
+fn number_of_lattice_points() {
+    assert_eq!(s(0, 0, 6, 4).number_of_lattice_points(), 3);
+    assert_eq!(s(0, 0, 0, 4).number_of_lattice_points(), 5);
+    assert_eq!(s(0, 0, 3, 5).number_of_lattice_points(), 2);
+}
+
+// Tests for point queries
+
+#[test]
+fn side_of() {
+    let segment = s(0, 0, 4, 2);
+    assert_eq!(segment.side_of(&p(0, 3)), Orientation2D::CounterClockwise);
+    assert_eq!(segment.side_of(&p(3, 0)), Orientation2D::Clockwise);
+    assert_eq!(segment.side_of(&p(8, 4)), Orientation2D::Collinear);
+}
+
+#[test]
+fn contains_point() {
+    let segment = s(0, 0, 4, 2);
+    assert!(segment.contains_point(&p(0, 0)));
+    assert!(segment.contains_point(&p(2, 1)));
+    assert!(segment.contains_point(&p(4, 2)));
+    assert!(!segment.contains_point(&p(6, 3)));
+    assert!(!segment.contains_point(&p(1, 1)));
+}
+
+// Tests for intersect
+
+#[test]
+fn intersect_crossing_at_lattice_point() {
+    let intersection = s(0, 0, 4, 4).intersect(&s(0, 4, 4, 0)).unwrap();
+    assert_eq!(
+        intersection,
+        SegmentIntersection2D::Point([r(2, 1), r(2, 1)])
+    );
+    assert_eq!(intersection.to_lattice_point(), Some(p(2, 2)));
+}
+
+#[test]
+fn intersect_crossing_at_rational_point() {
+    let intersection = s(0, 0, 1, 1).intersect(&s(0, 1, 1, 0)).unwrap();
+    assert_eq!(
+        intersection,
+        SegmentIntersection2D::Point([r(1, 2), r(1, 2)])
+    );
+    assert_eq!(intersection.to_lattice_point(), None);
+}
+
+#[test]
+fn intersect_touching_at_endpoint() {
+    let intersection = s(0, 0, 2, 2).intersect(&s(2, 2, 5, 0)).unwrap();
+    assert_eq!(intersection.to_lattice_point(), Some(p(2, 2)));
+}
+
+#[test]
+fn intersect_not_reaching() {
+    assert_eq!(s(0, 0, 1, 1).intersect(&s(3, 0, 0, 3)), None);
+    assert!(!s(0, 0, 1, 1).overlaps(&s(3, 0, 0, 3)));
+}
+
+#[test]
+fn intersect_parallel_disjoint() {
+    let a = s(0, 0, 4, 2);
+    let b = s(0, 1, 4, 3);
+    assert!(a.is_parallel(&b));
+    assert!(!a.is_collinear(&b));
+    assert_eq!(a.intersect(&b), None);
+}
+
+#[test]
+fn intersect_collinear_overlap() {
+    let a = s(0, 0, 6, 3);
+    let b = s(8, 4, 2, 1);
+    assert!(a.is_collinear(&b));
+    assert_eq!(
+        a.intersect(&b),
+        Some(SegmentIntersection2D::Segment(s(2, 1, 6, 3)))
+    );
+}
+
+#[test]
+fn intersect_collinear_end_to_end() {
+    let intersection = s(0, 0, 2, 1).intersect(&s(2, 1, 4, 2)).unwrap();
+    assert_eq!(intersection.to_lattice_point(), Some(p(2, 1)));
+}
+
+#[test]
+fn intersect_collinear_disjoint() {
+    assert_eq!(s(0, 0, 2, 1).intersect(&s(4, 2, 6, 3)), None);
+}
+
+// Tests for iterators
+
+#[test]
+fn iter_yields_lattice_points() {
+    let points: Vec<_> = s(0, 0, 6, 4).iter().collect();
+    assert_eq!(points, vec![p(0, 0), p(3, 2), p(6, 4)]);
+}
+
+#[test]
+fn iter_reversed_segment() {
+    let points: Vec<_> = s(2, 0, -2, 0).into_iter().collect();
+    assert_eq!(points, vec![p(2, 0), p(1, 0), p(0, 0), p(-1, 0), p(-2, 0)]);
+}
+
+#[test]
+fn rasterize_is_connected() {
+    let points: Vec<_> = s(0, 0, 5, -2).rasterize().collect();
+    assert_eq!(points.first(), Some(&p(0, 0)));
+    assert_eq!(points.last(), Some(&p(5, -2)));
+    assert_eq!(points.len(), 6);
+    assert!(points.windows(2).all(|w| {
+        let [a, b] = [w[0].get_coordinates(), w[1].get_coordinates()];
+        (a[0] - b[0]).abs() <= 1 && (a[1] - b[1]).abs() <= 1
+    }));
+}
+
+#[test]
+fn display() {
+    assert_eq!(s(1, 2, -3, 4).to_string(), "[(1,2),(-3,4)]");
+}
@@ -0,0 +1 @@
+pub mod brick_stack_3d;
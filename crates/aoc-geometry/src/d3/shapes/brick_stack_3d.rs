@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod brick_stack_3d_tests;
+
+use crate::HyperCube;
+use crate::Vector;
+use crate::generic::core::point::to_wide;
+use crate::generic::core::point_coordinate::PointCoordinate;
+use num_traits::cast::cast;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const DIMENSIONS: usize = 3;
+
+/// A pile of bricks that have fallen along an axis until resting on the floor or on other
+/// bricks.
+///
+/// Each brick is an axis-aligned box of integer points (see
+/// [`HyperCube::from_min_max_vertices`]). Besides the final position of the bricks, it keeps
+/// the support relation between them: a brick supports another one if the second one rests
+/// directly on top of the first one.
+///
+/// Bricks are identified by their index in the list used to create the stack.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of coordinate values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrickStack3D<T: PointCoordinate> {
+    /// The bricks in their resting positions.
+    bricks: Vec<HyperCube<T, DIMENSIONS>>,
+    /// For every brick, the bricks directly below it that hold it.
+    supporters: Vec<HashSet<usize>>,
+    /// For every brick, the bricks directly above it that rest on it.
+    supported: Vec<HashSet<usize>>,
+}
+
+impl<T: PointCoordinate> BrickStack3D<T> {
+    /// Drops all the bricks towards the lowest values of an axis, until every brick rests on
+    /// the floor or on another brick.
+    ///
+    /// Bricks fall in order of their lowest coordinate in the axis, and they never rotate nor
+    /// move in the other axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bricks` - The bricks in their initial positions
+    /// * `axis` - The index of the axis along which the bricks fall (for example, `2` for Z)
+    /// * `floor` - The lowest coordinate that a brick can occupy in the axis
+    ///
+    /// # Returns
+    ///
+    /// A new `BrickStack3D` with the bricks in their resting positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis index is out of bounds, or if any brick starts below the floor.
+    pub fn settle(bricks: &[HyperCube<T, DIMENSIONS>], axis: usize, floor: T) -> Self {
+        assert!(axis < DIMENSIONS, "Axis index out of bounds");
+        assert!(
+            bricks
+                .iter()
+                .all(|brick| brick.get_min_vertex()[axis] >= floor),
+            "Bricks cannot start below the floor."
+        );
+        let [first_axis, second_axis] = match axis {
+            0 => [1, 2],
+            1 => [0, 2],
+            _ => [0, 1],
+        };
+        let floor: i128 = cast(floor).unwrap();
+
+        let mut order: Vec<usize> = (0..bricks.len()).collect();
+        order.sort_by_key(|&index| bricks[index].get_min_vertex()[axis]);
+
+        let mut settled = bricks.to_vec();
+        let mut supporters = vec![HashSet::new(); bricks.len()];
+        let mut supported = vec![HashSet::new(); bricks.len()];
+        // highest occupied coordinate of every column, and the brick that occupies it
+        let mut tops: HashMap<[i128; 2], (i128, usize)> = HashMap::new();
+        for index in order {
+            let min = to_wide(bricks[index].get_min_vertex());
+            let max = to_wide(bricks[index].get_max_vertex());
+            let columns: Vec<[i128; 2]> = (min[first_axis]..=max[first_axis])
+                .flat_map(|a| (min[second_axis]..=max[second_axis]).map(move |b| [a, b]))
+                .collect();
+            let rest = columns
+                .iter()
+                .filter_map(|column| tops.get(column))
+                .map(|&(top, _)| top + 1)
+                .fold(floor, i128::max);
+            for column in &columns {
+                if let Some(&(top, below)) = tops.get(column)
+                    && top + 1 == rest
+                {
+                    supporters[index].insert(below);
+                    supported[below].insert(index);
+                }
+            }
+
+            let mut displacement = [0; DIMENSIONS];
+            displacement[axis] = rest - min[axis];
+            settled[index] = bricks[index]
+                .move_by(&Vector::<i128, DIMENSIONS>::new(displacement))
+                .expect("Brick is out of bounds.");
+            let top = rest + max[axis] - min[axis];
+            for column in columns {
+                tops.insert(column, (top, index));
+            }
+        }
+
+        Self {
+            bricks: settled,
+            supporters,
+            supported,
+        }
+    }
+
+    /// Returns all the bricks in their resting positions, in the original order.
+    pub fn get_bricks(&self) -> &Vec<HyperCube<T, DIMENSIONS>> {
+        &self.bricks
+    }
+
+    /// Returns the bricks directly below a brick that hold it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the brick
+    ///
+    /// # Returns
+    ///
+    /// The indexes of the supporting bricks. It is empty if the brick rests on the floor.
+    pub fn get_supporters(&self, index: usize) -> &HashSet<usize> {
+        &self.supporters[index]
+    }
+
+    /// Returns the bricks directly above a brick that rest on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the brick
+    ///
+    /// # Returns
+    ///
+    /// The indexes of the supported bricks.
+    pub fn get_supported(&self, index: usize) -> &HashSet<usize> {
+        &self.supported[index]
+    }
+
+    /// Checks if a brick can be removed without making any other brick fall, which happens
+    /// when every brick resting on it has another supporter.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the brick
+    pub fn is_removable(&self, index: usize) -> bool {
+        self.supported[index]
+            .iter()
+            .all(|&above| self.supporters[above].len() > 1)
+    }
+
+    /// Returns the bricks that can be removed without making any other brick fall.
+    ///
+    /// # Returns
+    ///
+    /// The indexes of the removable bricks, in ascending order.
+    pub fn get_removable_bricks(&self) -> Vec<usize> {
+        (0..self.bricks.len())
+            .filter(|&index| self.is_removable(index))
+            .collect()
+    }
+
+    /// Calculates how many other bricks would fall in a chain reaction if a brick were removed.
+    ///
+    /// A brick falls when all its supporters have been removed or have fallen.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the removed brick
+    ///
+    /// # Returns
+    ///
+    /// The number of falling bricks, not counting the removed one.
+    pub fn number_of_falling_bricks(&self, index: usize) -> usize {
+        let mut fallen: HashSet<usize> = HashSet::from([index]);
+        let mut pending: VecDeque<usize> = VecDeque::from([index]);
+        while let Some(current) = pending.pop_front() {
+            for &above in &self.supported[current] {
+                if !fallen.contains(&above)
+                    && self.supporters[above]
+                        .iter()
+                        .all(|below| fallen.contains(below))
+                {
+                    fallen.insert(above);
+                    pending.push_back(above);
+                }
+            }
+        }
+        fallen.len() - 1
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::Point;
+use pretty_assertions::assert_eq;
+
+fn brick(min: [i32; 3], max: [i32; 3]) -> HyperCube<i32, DIMENSIONS> {
+    HyperCube::from_min_max_vertices(&Point::new(min), &Point::new(max))
+}
+
+fn example() -> BrickStack3D<i32> {
+    let bricks = [
+        brick([1, 0, 1], [1, 2, 1]),
+        brick([0, 0, 2], [2, 0, 2]),
+        brick([0, 2, 3], [2, 2, 3]),
+        brick([0, 0, 4], [0, 2, 4]),
+        brick([2, 0, 5], [2, 2, 5]),
+        brick([0, 1, 6], [2, 1, 6]),
+        brick([1, 1, 8], [1, 1, 9]),
+    ];
+    BrickStack3D::settle(&bricks, 2, 1)
+}
+
+// Tests for settle
+
+#[test]
+fn settle_final_positions() {
+    let stack = example();
+    assert_eq!(
+        stack.get_bricks(),
+        &vec![
+            brick([1, 0, 1], [1, 2, 1]),
+            brick([0, 0, 2], [2, 0, 2]),
+            brick([0, 2, 2], [2, 2, 2]),
+            brick([0, 0, 3], [0, 2, 3]),
+            brick([2, 0, 3], [2, 2, 3]),
+            brick([0, 1, 4], [2, 1, 4]),
+            brick([1, 1, 5], [1, 1, 6]),
+        ]
+    );
+}
+
+#[test]
+fn settle_unordered_input() {
+    let bricks = [brick([0, 0, 7], [0, 0, 7]), brick([0, 0, 3], [0, 0, 4])];
+    let stack = BrickStack3D::settle(&bricks, 2, 0);
+    assert_eq!(
+        stack.get_bricks(),
+        &vec![brick([0, 0, 2], [0, 0, 2]), brick([0, 0, 0], [0, 0, 1])]
+    );
+    assert_eq!(stack.get_supporters(0), &HashSet::from([1]));
+}
+
+#[test]
+fn settle_along_other_axis() {
+    let bricks = [brick([5, 0, 0], [6, 0, 0]), brick([9, 0, 0], [9, 0, 1])];
+    let stack = BrickStack3D::settle(&bricks, 0, 0);
+    assert_eq!(
+        stack.get_bricks(),
+        &vec![brick([0, 0, 0], [1, 0, 0]), brick([2, 0, 0], [2, 0, 1])]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bricks cannot start below the floor.")]
+fn settle_below_floor_panics() {
+    BrickStack3D::settle(&[brick([0, 0, 0], [0, 0, 0])], 2, 1);
+}
+
+// Tests for the support relation
+
+#[test]
+fn supporters_and_supported() {
+    let stack = example();
+    assert_eq!(stack.get_supporters(0), &HashSet::new());
+    assert_eq!(stack.get_supported(0), &HashSet::from([1, 2]));
+    assert_eq!(stack.get_supporters(3), &HashSet::from([1, 2]));
+    assert_eq!(stack.get_supporters(6), &HashSet::from([5]));
+    assert_eq!(stack.get_supported(6), &HashSet::new());
+}
+
+#[test]
+fn removable_bricks() {
+    let stack = example();
+    assert!(!stack.is_removable(0));
+    assert!(stack.is_removable(1));
+    assert_eq!(stack.get_removable_bricks(), vec![1, 2, 3, 4, 6]);
+}
+
+#[test]
+fn number_of_falling_bricks() {
+    let stack = example();
+    assert_eq!(stack.number_of_falling_bricks(0), 6);
+    assert_eq!(stack.number_of_falling_bricks(5), 1);
+    assert_eq!(stack.number_of_falling_bricks(1), 0);
+    assert_eq!(
+        (0..7)
+            .map(|i| stack.number_of_falling_bricks(i))
+            .sum::<usize>(),
+        7
+    );
+}
@@ -194,3 +194,8 @@ impl<T: PointCoordinate, const N: usize> std::ops::Index<usize> for Point<T, N>
         self.get(index)
     }
 }
+
+/// Converts the coordinates of a point into `i128`, so no operation can overflow.
+pub(crate) fn to_wide<T: PointCoordinate, const N: usize>(point: &Point<T, N>) -> [i128; N] {
+    point.get_coordinates().map(|c| cast(c).unwrap())
+}
@@ -2,3 +2,4 @@ pub mod bounding_box;
 pub mod hypercube;
 pub mod line;
 pub mod position_status;
+pub mod ray;
@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod hypercube_tests;
+
 use crate::BoundingBox;
 use crate::Point;
 use crate::PositionStatus;
@@ -42,26 +45,10 @@ impl<T: PointCoordinate, const N: usize> HyperCube<T, N> {
         let vertex2_opt = vertex.move_by(diagonal);
         assert!(vertex2_opt.is_some(), "Other vertex is out of bounds.");
         let vertex2 = vertex2_opt.unwrap();
-        let most_negative_point = Point::new([T::min_value(); N]);
-        if Vector::<i128, N>::from_points(&most_negative_point, vertex)
-            .unwrap()
-            .manhattan_distance()
-            < Vector::<i128, N>::from_points(&most_negative_point, &vertex2)
-                .unwrap()
-                .manhattan_distance()
-        {
-            Self {
-                min_vertex: *vertex,
-                max_vertex: vertex2,
-                sizes: diagonal.absolute_coordinates(),
-            }
-        } else {
-            Self {
-                min_vertex: vertex2,
-                max_vertex: *vertex,
-                sizes: diagonal.absolute_coordinates(),
-            }
-        }
+        Self::from_min_max_vertices(
+            &Point::new(std::array::from_fn(|i| vertex[i].min(vertex2[i]))),
+            &Point::new(std::array::from_fn(|i| vertex[i].max(vertex2[i]))),
+        )
     }
 
     pub fn from_opposite_vertices(vertex1: &Point<T, N>, vertex2: &Point<T, N>) -> Self {
@@ -70,6 +57,65 @@ impl<T: PointCoordinate, const N: usize> HyperCube<T, N> {
         HyperCube::from_vertex_and_diagonal(vertex1, &diagonal)
     }
 
+    /// Creates a new hypercube from its vertex with the lowest coordinates and its vertex with
+    /// the highest coordinates.
+    ///
+    /// Unlike the other constructors, the hypercube can be flat (both vertices have the same
+    /// value in some axis), which is needed to represent any set of integer points, like a
+    /// single point or a line.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_vertex` - The vertex with the lowest value in every axis
+    /// * `max_vertex` - The vertex with the highest value in every axis
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_vertex` is greater than `max_vertex` in any axis.
+    pub fn from_min_max_vertices(min_vertex: &Point<T, N>, max_vertex: &Point<T, N>) -> Self {
+        assert!(
+            (0..N).all(|i| min_vertex[i] <= max_vertex[i]),
+            "Minimum vertex cannot be greater than the maximum vertex in any axis."
+        );
+        Self {
+            min_vertex: *min_vertex,
+            max_vertex: *max_vertex,
+            sizes: Vector::<i128, N>::from_points(min_vertex, max_vertex)
+                .expect("Diagonal vector cannot be created")
+                .absolute_coordinates(),
+        }
+    }
+
+    /// Returns the vertex with the lowest value in every axis.
+    pub fn get_min_vertex(&self) -> &Point<T, N> {
+        &self.min_vertex
+    }
+
+    /// Returns the vertex with the highest value in every axis.
+    pub fn get_max_vertex(&self) -> &Point<T, N> {
+        &self.max_vertex
+    }
+
+    /// Creates a new hypercube by moving this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - The displacement to apply to all the vertices
+    ///
+    /// # Returns
+    ///
+    /// The moved hypercube, or `None` if any vertex goes out of bounds.
+    pub fn move_by<U>(&self, vector: &Vector<U, N>) -> Option<Self>
+    where
+        U: VectorCoordinate,
+    {
+        Some(Self {
+            min_vertex: self.min_vertex.move_by(vector)?,
+            max_vertex: self.max_vertex.move_by(vector)?,
+            sizes: self.sizes,
+        })
+    }
+
     pub fn get_sizes(&self) -> [u64; N] {
         self.sizes
     }
@@ -121,4 +167,132 @@ impl<T: PointCoordinate, const N: usize> HyperCube<T, N> {
         }
         vertices
     }
+
+    /// Calculates the number of points with integer coordinates covered by this hypercube,
+    /// borders included.
+    pub fn count(&self) -> u128 {
+        self.sizes.iter().map(|&size| size as u128 + 1).product()
+    }
+
+    /// Checks if a point is covered by this hypercube, borders included.
+    pub fn contains(&self, point: &Point<T, N>) -> bool {
+        !self.is_outside(point)
+    }
+
+    /// Checks if every point of another hypercube is also covered by this hypercube.
+    pub fn subsumes(&self, other: &Self) -> bool {
+        (0..N).all(|i| {
+            self.min_vertex[i] <= other.min_vertex[i] && other.max_vertex[i] <= self.max_vertex[i]
+        })
+    }
+
+    /// Checks if both hypercubes share at least one point, borders included.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (0..N).all(|i| {
+            self.min_vertex[i] <= other.max_vertex[i] && other.min_vertex[i] <= self.max_vertex[i]
+        })
+    }
+
+    /// Calculates the hypercube covered by both hypercubes, borders included.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other hypercube
+    ///
+    /// # Returns
+    ///
+    /// The common hypercube, which may be flat if both only share a border, or `None` if they
+    /// do not touch.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Self::from_min_max_vertices(
+            &Point::new(std::array::from_fn(|i| {
+                self.min_vertex[i].max(other.min_vertex[i])
+            })),
+            &Point::new(std::array::from_fn(|i| {
+                self.max_vertex[i].min(other.max_vertex[i])
+            })),
+        ))
+    }
+
+    /// Removes the integer points of another hypercube from this hypercube.
+    ///
+    /// The pieces are cut in the same way as in `IntervalBox::subtract` of the `aoc-intervals`
+    /// crate, which describes the decomposition, so there are `2 * N` of them at most.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The hypercube to remove
+    ///
+    /// # Returns
+    ///
+    /// A list of disjoint hypercubes that cover exactly the integer points of this hypercube
+    /// which are not in `other`. It is empty if `other` covers this hypercube completely.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersect(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::with_capacity(2 * N);
+        let mut remaining = *self;
+        for i in 0..N {
+            if remaining.min_vertex[i] < common.min_vertex[i] {
+                let mut max_coordinates = *remaining.max_vertex.get_coordinates();
+                max_coordinates[i] = common.min_vertex[i] - T::one();
+                pieces.push(Self::from_min_max_vertices(
+                    &remaining.min_vertex,
+                    &Point::new(max_coordinates),
+                ));
+            }
+            if common.max_vertex[i] < remaining.max_vertex[i] {
+                let mut min_coordinates = *remaining.min_vertex.get_coordinates();
+                min_coordinates[i] = common.max_vertex[i] + T::one();
+                pieces.push(Self::from_min_max_vertices(
+                    &Point::new(min_coordinates),
+                    &remaining.max_vertex,
+                ));
+            }
+            // keep only the slab of the removed part in this axis
+            let mut min_coordinates = *remaining.min_vertex.get_coordinates();
+            let mut max_coordinates = *remaining.max_vertex.get_coordinates();
+            min_coordinates[i] = common.min_vertex[i];
+            max_coordinates[i] = common.max_vertex[i];
+            remaining = Self::from_min_max_vertices(
+                &Point::new(min_coordinates),
+                &Point::new(max_coordinates),
+            );
+        }
+        pieces
+    }
+
+    /// Calculates the number of integer points covered by at least one of the hypercubes.
+    ///
+    /// Every hypercube is split into pieces that do not overlap the previous ones, so each
+    /// point is only counted once.
+    ///
+    /// # Arguments
+    ///
+    /// * `hypercubes` - The hypercubes to join
+    pub fn count_union<'a, I>(hypercubes: I) -> u128
+    where
+        I: IntoIterator<Item = &'a Self>,
+        T: 'a,
+    {
+        let mut disjoint: Vec<Self> = Vec::new();
+        for hypercube in hypercubes {
+            let mut pieces = vec![*hypercube];
+            for existing in &disjoint {
+                pieces = pieces
+                    .iter()
+                    .flat_map(|piece| piece.subtract(existing))
+                    .collect();
+                if pieces.is_empty() {
+                    break;
+                }
+            }
+            disjoint.extend(pieces);
+        }
+        disjoint.iter().map(|hypercube| hypercube.count()).sum()
+    }
 }
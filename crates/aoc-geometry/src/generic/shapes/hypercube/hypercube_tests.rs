@@ -0,0 +1,139 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn cube(min: [i32; 3], max: [i32; 3]) -> HyperCube<i32, 3> {
+    HyperCube::from_min_max_vertices(&Point::new(min), &Point::new(max))
+}
+
+fn square(min: [i32; 2], max: [i32; 2]) -> HyperCube<i32, 2> {
+    HyperCube::from_min_max_vertices(&Point::new(min), &Point::new(max))
+}
+
+// Tests for constructors
+
+#[test]
+fn from_vertex_and_diagonal_sorts_vertices() {
+    let hypercube = HyperCube::from_vertex_and_diagonal(&Point::new([5, 1]), &Vector::new([-3, 4]));
+    assert_eq!(hypercube.get_min_vertex(), &Point::new([2, 1]));
+    assert_eq!(hypercube.get_max_vertex(), &Point::new([5, 5]));
+    assert_eq!(hypercube.get_sizes(), [3, 4]);
+}
+
+#[test]
+fn from_min_max_vertices_flat() {
+    let hypercube = cube([1, 2, 3], [1, 2, 7]);
+    assert_eq!(hypercube.get_sizes(), [0, 0, 4]);
+    assert_eq!(hypercube.area(), 0);
+    assert_eq!(hypercube.count(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Minimum vertex cannot be greater than the maximum vertex in any axis.")]
+fn from_min_max_vertices_unordered_panics() {
+    let _hypercube = square([0, 5], [3, 4]);
+}
+
+#[test]
+fn count() {
+    assert_eq!(cube([10, 10, 10], [12, 12, 12]).count(), 27);
+    assert_eq!(square([-2, -2], [-2, -2]).count(), 1);
+}
+
+// Tests for contains, subsumes, overlaps and intersect
+
+#[test]
+fn contains() {
+    let big = square([0, 0], [9, 9]);
+    assert!(big.contains(&Point::new([0, 5])));
+    assert!(big.contains(&Point::new([4, 4])));
+    assert!(!big.contains(&Point::new([10, 4])));
+}
+
+#[test]
+fn subsumes_and_overlaps() {
+    let big = square([0, 0], [9, 9]);
+    assert!(big.subsumes(&square([2, 3], [9, 4])));
+    assert!(!big.subsumes(&square([2, 3], [10, 4])));
+    assert!(big.overlaps(&square([9, 9], [12, 12])));
+    assert!(!big.overlaps(&square([10, 0], [12, 12])));
+}
+
+#[test]
+fn intersect() {
+    let a = cube([10, 10, 10], [12, 12, 12]);
+    let b = cube([11, 11, 11], [13, 13, 13]);
+    assert_eq!(a.intersect(&b), Some(cube([11, 11, 11], [12, 12, 12])));
+    assert_eq!(
+        a.intersect(&cube([12, 0, 0], [20, 20, 20])),
+        Some(cube([12, 10, 10], [12, 12, 12]))
+    );
+    assert_eq!(a.intersect(&cube([13, 0, 0], [20, 20, 20])), None);
+}
+
+// Tests for subtract
+
+#[test]
+fn subtract_disjoint() {
+    let a = square([0, 0], [3, 3]);
+    assert_eq!(a.subtract(&square([5, 5], [6, 6])), vec![a]);
+}
+
+#[test]
+fn subtract_covering() {
+    let a = square([1, 1], [3, 3]);
+    assert_eq!(a.subtract(&square([0, 0], [3, 3])), vec![]);
+}
+
+#[test]
+fn subtract_hole() {
+    let a = cube([0, 0, 0], [4, 4, 4]);
+    let hole = cube([1, 1, 1], [3, 3, 3]);
+    let pieces = a.subtract(&hole);
+    assert_eq!(pieces.len(), 6);
+    assert_eq!(pieces.iter().map(|p| p.count()).sum::<u128>(), 125 - 27);
+    assert!(pieces.iter().all(|p| !p.overlaps(&hole)));
+    assert!(
+        pieces
+            .iter()
+            .enumerate()
+            .all(|(i, p)| pieces[i + 1..].iter().all(|q| !p.overlaps(q)))
+    );
+}
+
+#[test]
+fn subtract_corner() {
+    let pieces = square([0, 0], [3, 3]).subtract(&square([2, 2], [5, 5]));
+    assert_eq!(pieces, vec![square([0, 0], [1, 3]), square([2, 0], [3, 1])]);
+}
+
+// Tests for count_union
+
+#[test]
+fn count_union() {
+    let cubes = [
+        cube([10, 10, 10], [12, 12, 12]),
+        cube([11, 11, 11], [13, 13, 13]),
+        cube([10, 10, 10], [10, 10, 10]),
+    ];
+    assert_eq!(HyperCube::count_union(&cubes), 46);
+}
+
+#[test]
+fn count_union_empty() {
+    assert_eq!(HyperCube::<i32, 2>::count_union(&[]), 0);
+}
+
+// Tests for move_by
+
+#[test]
+fn move_by() {
+    let hypercube = cube([1, 0, 1], [1, 2, 1]);
+    assert_eq!(
+        hypercube.move_by(&Vector::new([0, 0, -1])),
+        Some(cube([1, 0, 0], [1, 2, 0]))
+    );
+    assert_eq!(
+        square([0, 0], [3, 3]).move_by(&Vector::new([i32::MAX, 0])),
+        None
+    );
+}
@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod ray_tests;
+
+use crate::Point;
+use crate::Vector;
+use crate::generic::core::point::to_wide;
+use crate::generic::core::vector_coordinate::VectorCoordinate;
+use num_rational::Ratio;
+use num_traits::cast::cast;
+
+/// The way the paths of two rays meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RayIntersection<const N: usize> {
+    /// The paths cross at a single point, which may have non-integer coordinates.
+    Crossing {
+        /// The exact position where both paths cross.
+        position: [Ratio<i128>; N],
+        /// The time at which the first ray passes through the crossing point.
+        time: Ratio<i128>,
+        /// The time at which the other ray passes through the crossing point.
+        other_time: Ratio<i128>,
+    },
+    /// Both rays move along the same infinite line, so their paths share all their points.
+    Coincident,
+}
+
+/// A point moving with constant velocity in N-dimensional space.
+///
+/// It is useful to model trajectories, like particles or hailstones, where the position at
+/// time `t` is `origin + t * velocity`. All the intersection and collision calculations are
+/// done with exact `i128` and rational arithmetic.
+///
+/// # Type Parameters
+///
+/// * `T` - The signed numeric type for coordinates.
+/// * `N` - The number of dimensions (must be known at compile time).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ray<T: VectorCoordinate, const N: usize> {
+    origin: Point<T, N>,
+    velocity: Vector<T, N>,
+}
+
+impl<T: VectorCoordinate, const N: usize> Ray<T, N> {
+    /// Creates a new ray from its position at time zero and its velocity.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The position at time zero
+    /// * `velocity` - The displacement per time unit
+    ///
+    /// # Panics
+    ///
+    /// Panics if the velocity is zero, as it cannot define a direction.
+    pub fn new(origin: Point<T, N>, velocity: Vector<T, N>) -> Self {
+        assert!(
+            !velocity.is_zero(),
+            "Velocity must be non-zero to form a ray."
+        );
+        Self { origin, velocity }
+    }
+
+    /// Returns the position at time zero.
+    pub fn get_origin(&self) -> &Point<T, N> {
+        &self.origin
+    }
+
+    /// Returns the displacement per time unit.
+    pub fn get_velocity(&self) -> &Vector<T, N> {
+        &self.velocity
+    }
+
+    /// Calculates the position after an integer amount of time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The elapsed time. It can be negative.
+    ///
+    /// # Returns
+    ///
+    /// The position, or `None` if it does not fit in `T`.
+    pub fn position_at(&self, time: i64) -> Option<Point<T, N>> {
+        let [p, v] = [self.wide_origin(), self.wide_velocity()];
+        let mut coordinates = [T::zero(); N];
+        for i in 0..N {
+            let c = v[i].checked_mul(time as i128)?.checked_add(p[i])?;
+            coordinates[i] = cast(c)?;
+        }
+        Some(Point::new(coordinates))
+    }
+
+    /// Calculates the exact position at any rational time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The elapsed time. It can be negative.
+    pub fn exact_position_at(&self, time: Ratio<i128>) -> [Ratio<i128>; N] {
+        let [p, v] = [self.wide_origin(), self.wide_velocity()];
+        std::array::from_fn(|i| Ratio::from_integer(p[i]) + time * v[i])
+    }
+
+    /// Creates a new ray keeping only some of the axes, in the given order.
+    ///
+    /// For example, `[0, 1]` projects a 3-D ray into the XY plane.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - The indexes of the axes to keep
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds, or if the projected velocity is zero.
+    pub fn project<const M: usize>(&self, axes: [usize; M]) -> Ray<T, M> {
+        Ray::new(
+            Point::new(axes.map(|i| self.origin[i])),
+            Vector::new(axes.map(|i| self.velocity[i])),
+        )
+    }
+
+    /// Checks if both rays move along parallel lines (coincident lines included).
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        are_parallel(&self.wide_velocity(), &other.wide_velocity())
+    }
+
+    /// Checks if both rays move along the same infinite line, whatever their speed or sense.
+    pub fn is_coincident(&self, other: &Self) -> bool {
+        let offset = subtract(&other.wide_origin(), &self.wide_origin());
+        self.is_parallel(other) && are_parallel(&offset, &self.wide_velocity())
+    }
+
+    /// Calculates where the paths of both rays cross, regardless of whether both rays are
+    /// there at the same time.
+    ///
+    /// The times of the crossing may be negative, which means that the crossing happened in the
+    /// past. Filter them if only the future matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other ray
+    ///
+    /// # Returns
+    ///
+    /// `None` if the paths never meet (they are parallel or skew). Otherwise, the crossing point
+    /// and the time at which each ray reaches it, or `Coincident` if both paths are on the same
+    /// line.
+    pub fn path_intersection(&self, other: &Self) -> Option<RayIntersection<N>> {
+        let r = self.wide_velocity();
+        let s = other.wide_velocity();
+        if are_parallel(&r, &s) {
+            return self
+                .is_coincident(other)
+                .then_some(RayIntersection::Coincident);
+        }
+
+        // solve `p + t * r = q + u * s` on two axes where the velocities are independent
+        let d = subtract(&other.wide_origin(), &self.wide_origin());
+        let (i, j, denominator) = (0..N)
+            .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+            .map(|(i, j)| (i, j, r[i] * s[j] - r[j] * s[i]))
+            .find(|&(_, _, denominator)| denominator != 0)?;
+        let time = Ratio::new(d[i] * s[j] - d[j] * s[i], denominator);
+        let other_time = Ratio::new(d[i] * r[j] - d[j] * r[i], denominator);
+
+        // the remaining axes must agree, or the lines are skew
+        let position = self.exact_position_at(time);
+        if position != other.exact_position_at(other_time) {
+            return None;
+        }
+        Some(RayIntersection::Crossing {
+            position,
+            time,
+            other_time,
+        })
+    }
+
+    /// Calculates when both rays are at the same position at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other ray
+    ///
+    /// # Returns
+    ///
+    /// The exact time of the collision, which may be negative or non-integer, or `None` if they
+    /// never collide. If both rays are always at the same position, it returns zero.
+    pub fn collision_time(&self, other: &Self) -> Option<Ratio<i128>> {
+        // `p + t * r = q + t * s`  =>  `q - p = t * (r - s)`
+        let d = subtract(&other.wide_origin(), &self.wide_origin());
+        let w = subtract(&self.wide_velocity(), &other.wide_velocity());
+        let time = match (0..N).find(|&i| w[i] != 0) {
+            Some(i) => Ratio::new(d[i], w[i]),
+            None => Ratio::from_integer(0),
+        };
+        (0..N)
+            .all(|i| Ratio::from_integer(d[i]) == time * w[i])
+            .then_some(time)
+    }
+
+    /// Converts the position at time zero into `i128`.
+    fn wide_origin(&self) -> [i128; N] {
+        to_wide(&self.origin)
+    }
+
+    /// Converts the velocity into `i128`.
+    fn wide_velocity(&self) -> [i128; N] {
+        self.velocity.get_coordinates().map(|c| cast(c).unwrap())
+    }
+}
+
+impl<T: VectorCoordinate> Ray<T, 3> {
+    /// Finds the ray that collides with every ray of the list, each one at a different time.
+    ///
+    /// Only three rays are needed to define the solution: seen from the first ray, which
+    /// becomes still at the origin, the solution must lie on the planes that contain the origin
+    /// and the other two rays. Crossing each ray with the plane of the other one gives two
+    /// collision times and points, and the solution is the ray through both points. The rest
+    /// of the rays are used to verify the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `rays` - The rays to hit. At least three of them must be in general position.
+    ///
+    /// # Returns
+    ///
+    /// The ray with integer position and velocity that collides with all the rays, or `None`
+    /// if there is no such ray.
+    pub fn find_colliding_ray(rays: &[Self]) -> Option<Self> {
+        rays.windows(3)
+            .filter_map(|triple| {
+                Self::colliding_ray_from_triple(&triple[0], &triple[1], &triple[2])
+            })
+            .find(|candidate| {
+                rays.iter()
+                    .all(|ray| candidate.collision_time(ray).is_some())
+            })
+    }
+
+    /// Calculates the ray that collides with three rays, assuming that they are in general
+    /// position (see `find_colliding_ray`).
+    fn colliding_ray_from_triple(first: &Self, second: &Self, third: &Self) -> Option<Self> {
+        let (p0, v0) = (first.wide_origin(), first.wide_velocity());
+        let relative = |ray: &Self| {
+            (
+                subtract(&ray.wide_origin(), &p0),
+                subtract(&ray.wide_velocity(), &v0),
+            )
+        };
+        let (p1, v1) = relative(second);
+        let (p2, v2) = relative(third);
+
+        // normals of the planes through the origin containing each relative ray
+        let n1 = cross(&p1, &v1);
+        let n2 = cross(&p2, &v2);
+        let denominator1 = dot(&v1, &n2);
+        let denominator2 = dot(&v2, &n1);
+        if denominator1 == 0 || denominator2 == 0 {
+            return None;
+        }
+        let t1 = Ratio::new(-dot(&p1, &n2), denominator1);
+        let t2 = Ratio::new(-dot(&p2, &n1), denominator2);
+        if t1 == t2 {
+            return None;
+        }
+
+        let c1 = second.exact_position_at(t1);
+        let c2 = third.exact_position_at(t2);
+        let velocity: [Ratio<i128>; 3] = std::array::from_fn(|i| (c2[i] - c1[i]) / (t2 - t1));
+        let origin: [Ratio<i128>; 3] = std::array::from_fn(|i| c1[i] - t1 * velocity[i]);
+        let to_integers = |values: [Ratio<i128>; 3]| -> Option<[T; 3]> {
+            let mut coordinates = [T::zero(); 3];
+            for i in 0..3 {
+                if !values[i].is_integer() {
+                    return None;
+                }
+                coordinates[i] = cast(values[i].to_integer())?;
+            }
+            Some(coordinates)
+        };
+        let velocity = Vector::new(to_integers(velocity)?);
+        if velocity.is_zero() {
+            return None;
+        }
+        Some(Ray::new(Point::new(to_integers(origin)?), velocity))
+    }
+}
+
+/// Display formatting for rays.
+///
+/// Formats the ray as "(x,y,z) @ (vx,vy,vz)", like the trajectories of the puzzles.
+impl<T: VectorCoordinate, const N: usize> std::fmt::Display for Ray<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} @ {}", self.origin, self.velocity)
+    }
+}
+
+/// Calculates `a - b` coordinate by coordinate.
+fn subtract<const N: usize>(a: &[i128; N], b: &[i128; N]) -> [i128; N] {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+/// Checks if two vectors are parallel, which happens when all their 2x2 minors are zero.
+fn are_parallel<const N: usize>(a: &[i128; N], b: &[i128; N]) -> bool {
+    (0..N).all(|i| (i + 1..N).all(|j| a[i] * b[j] == a[j] * b[i]))
+}
+
+fn dot(a: &[i128; 3], b: &[i128; 3]) -> i128 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: &[i128; 3], b: &[i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
@@ -0,0 +1,158 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn ray3(origin: [i64; 3], velocity: [i64; 3]) -> Ray<i64, 3> {
+    Ray::new(Point::new(origin), Vector::new(velocity))
+}
+
+fn ray2(origin: [i64; 2], velocity: [i64; 2]) -> Ray<i64, 2> {
+    Ray::new(Point::new(origin), Vector::new(velocity))
+}
+
+fn r(numerator: i128, denominator: i128) -> Ratio<i128> {
+    Ratio::new(numerator, denominator)
+}
+
+fn hailstones() -> Vec<Ray<i64, 3>> {
+    vec![
+        ray3([19, 13, 30], [-2, 1, -2]),
+        ray3([18, 19, 22], [-1, -1, -2]),
+        ray3([20, 25, 34], [-2, -2, -4]),
+        ray3([12, 31, 28], [-1, -2, -1]),
+        ray3([20, 19, 15], [1, -5, -3]),
+    ]
+}
+
+#[test]
+#[should_panic(expected = "Velocity must be non-zero to form a ray.")]
+fn new_zero_velocity_panics() {
+    let _ray = ray2([1, 2], [0, 0]);
+}
+
+#[test]
+fn position_at() {
+    let ray = ray3([19, 13, 30], [-2, 1, -2]);
+    assert_eq!(ray.position_at(5), Some(Point::new([9, 18, 20])));
+    assert_eq!(ray.position_at(-1), Some(Point::new([21, 12, 32])));
+    assert_eq!(ray2([i64::MAX, 0], [1, 0]).position_at(1), None);
+}
+
+#[test]
+fn exact_position_at() {
+    let ray = ray2([0, 1], [3, -2]);
+    assert_eq!(ray.exact_position_at(r(1, 2)), [r(3, 2), r(0, 1)]);
+}
+
+#[test]
+fn project() {
+    let ray = ray3([19, 13, 30], [-2, 1, -2]).project([0, 1]);
+    assert_eq!(ray, ray2([19, 13], [-2, 1]));
+}
+
+#[test]
+fn parallel_and_coincident() {
+    let ray = ray2([0, 0], [1, 2]);
+    assert!(ray.is_parallel(&ray2([5, 0], [-2, -4])));
+    assert!(!ray.is_coincident(&ray2([5, 0], [-2, -4])));
+    assert!(ray.is_coincident(&ray2([3, 6], [-2, -4])));
+    assert!(!ray.is_parallel(&ray2([3, 6], [2, -4])));
+}
+
+#[test]
+fn path_intersection_crossing() {
+    let a = ray2([19, 13], [-2, 1]);
+    let b = ray2([18, 19], [-1, -1]);
+    let Some(RayIntersection::Crossing {
+        position,
+        time,
+        other_time,
+    }) = a.path_intersection(&b)
+    else {
+        panic!("The paths must cross");
+    };
+    assert_eq!(position, [r(43, 3), r(46, 3)]);
+    assert_eq!(time, r(7, 3));
+    assert_eq!(other_time, r(11, 3));
+}
+
+#[test]
+fn path_intersection_in_the_past() {
+    let a = ray2([19, 13], [-2, 1]);
+    let b = ray2([20, 19], [1, -5]);
+    let Some(RayIntersection::Crossing { time, .. }) = a.path_intersection(&b) else {
+        panic!("The paths must cross");
+    };
+    assert!(time < r(0, 1));
+}
+
+#[test]
+fn path_intersection_parallel() {
+    let a = ray2([18, 19], [-1, -1]);
+    let b = ray2([20, 25], [-2, -2]);
+    assert_eq!(a.path_intersection(&b), None);
+    let c = ray2([20, 21], [3, 3]);
+    assert_eq!(a.path_intersection(&c), Some(RayIntersection::Coincident));
+}
+
+#[test]
+fn path_intersection_skew() {
+    let a = ray3([0, 0, 0], [1, 0, 0]);
+    let b = ray3([0, 1, 1], [0, 0, 1]);
+    assert_eq!(a.path_intersection(&b), None);
+}
+
+#[test]
+fn path_intersection_future_crossings_inside_area() {
+    let rays: Vec<_> = hailstones().iter().map(|ray| ray.project([0, 1])).collect();
+    let area = r(7, 1)..=r(27, 1);
+    let zero = r(0, 1);
+    let crossings = rays
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| rays[i + 1..].iter().map(move |b| (a, b)))
+        .filter(|(a, b)| match a.path_intersection(b) {
+            Some(RayIntersection::Crossing {
+                position,
+                time,
+                other_time,
+            }) => time >= zero && other_time >= zero && position.iter().all(|c| area.contains(c)),
+            _ => false,
+        })
+        .count();
+    assert_eq!(crossings, 2);
+}
+
+#[test]
+fn collision_time() {
+    let rock = ray3([24, 13, 10], [-3, 1, 2]);
+    let hailstone = ray3([19, 13, 30], [-2, 1, -2]);
+    assert_eq!(rock.collision_time(&hailstone), Some(r(5, 1)));
+    assert_eq!(rock.collision_time(&ray3([0, 0, 0], [1, 1, 1])), None);
+    assert_eq!(rock.collision_time(&rock), Some(r(0, 1)));
+}
+
+#[test]
+fn find_colliding_ray() {
+    assert_eq!(
+        Ray::find_colliding_ray(&hailstones()),
+        Some(ray3([24, 13, 10], [-3, 1, 2]))
+    );
+}
+
+#[test]
+fn find_colliding_ray_none() {
+    let rays = vec![
+        ray3([0, 0, 0], [1, 0, 0]),
+        ray3([0, 1, 0], [1, 0, 0]),
+        ray3([0, 2, 0], [1, 0, 0]),
+    ];
+    assert_eq!(Ray::find_colliding_ray(&rays), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        ray3([19, 13, 30], [-2, 1, -2]).to_string(),
+        "(19,13,30) @ (-2,1,-2)"
+    );
+}
@@ -1,12 +1,21 @@
 mod d2;
+mod d3;
 mod generic;
 
 pub use d2::core::cardinal_direction_2d::CardinalDirection2D;
 pub use d2::core::direction_2d::Direction2D;
+pub use d2::core::flat_hex_direction_2d::FlatHexDirection2D;
+pub use d2::core::hex_coordinate_2d::HexCoordinate2D;
+pub use d2::core::orientation_2d::Orientation2D;
+pub use d2::core::parse_direction_error::ParseDirectionError;
+pub use d2::core::pointy_hex_direction_2d::PointyHexDirection2D;
 pub use d2::shapes::grid_2d::{Grid2D, GridCoordinate2D};
 pub use d2::shapes::orthogonal_polygon_2d::OrthogonalPolygon2D;
+pub use d2::shapes::polygon_2d::Polygon2D;
+pub use d2::shapes::segment_2d::{Segment2D, SegmentIntersection2D};
 pub use d2::shapes::square_diamond_2d::SquareDiamond2D;
 pub use d2::shapes::orthogonal_line_2d::OrthogonalLine2D;
+pub use d3::shapes::brick_stack_3d::BrickStack3D;
 pub use generic::core::axis_direction::AxisDirection;
 pub use generic::core::point::Point;
 pub use generic::core::vector::{Vector, VectorType};
@@ -14,3 +23,4 @@ pub use generic::shapes::bounding_box::BoundingBox;
 pub use generic::shapes::hypercube::HyperCube;
 pub use generic::shapes::line::Line;
 pub use generic::shapes::position_status::PositionStatus;
+pub use generic::shapes::ray::{Ray, RayIntersection};
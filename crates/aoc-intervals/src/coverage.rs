@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod coverage_tests;
+
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::interval_value::{IntervalValue, maximum_interval_value};
+use std::collections::BTreeMap;
+
+/// Represents how many times every value is covered by a collection of intervals.
+///
+/// It is calculated with a sweep line over the boundaries of the intervals, so it takes
+/// `O(n log n)` time regardless of the size of the intervals. The covered values are split into
+/// segments, which are the maximal intervals where the depth (the number of intervals containing
+/// the values) is constant.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the interval boundaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage<T: IntervalValue> {
+    /// Stores the covered segments and their depths, in ascending order.
+    segments: Vec<(Interval<T>, usize)>,
+}
+
+impl<T: IntervalValue> Coverage<T> {
+    /// Calculates the coverage of a collection of intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `intervals` - The intervals, which can overlap or be repeated.
+    ///
+    /// # Returns
+    ///
+    /// A new `Coverage` with the depth of every covered segment.
+    pub fn from_intervals(intervals: &[Interval<T>]) -> Self {
+        // the depth changes at the start of every interval and after its end
+        let mut changes: BTreeMap<T, i64> = BTreeMap::new();
+        for interval in intervals {
+            *changes.entry(interval.get_min()).or_default() += 1;
+            if let Some(end) = interval.get_end_exclusive() {
+                *changes.entry(end).or_default() -= 1;
+            }
+        }
+
+        let mut segments: Vec<(Interval<T>, usize)> = Vec::new();
+        let mut depth: i64 = 0;
+        let mut changes = changes
+            .into_iter()
+            .filter(|&(_, delta)| delta != 0)
+            .peekable();
+        while let Some((start, delta)) = changes.next() {
+            depth += delta;
+            if depth == 0 {
+                continue;
+            }
+            let max = match changes.peek() {
+                Some(&(next_start, _)) => next_start - T::one(),
+                None => maximum_interval_value(),
+            };
+            segments.push((Interval::from_boundaries(start, max), depth as usize));
+        }
+        Self { segments }
+    }
+
+    /// Gets the covered segments, in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// A slice with the maximal intervals of constant depth, and their depths. The values that
+    /// are not covered by any interval are not included.
+    pub fn get_segments(&self) -> &[(Interval<T>, usize)] {
+        &self.segments
+    }
+
+    /// Gets the number of intervals containing a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to check.
+    ///
+    /// # Returns
+    ///
+    /// The depth of the segment containing the value, or 0 if it is not covered.
+    pub fn depth_at(&self, value: T) -> usize {
+        let index = self
+            .segments
+            .partition_point(|(segment, _)| segment.get_max() < value);
+        self.segments
+            .get(index)
+            .filter(|(segment, _)| segment.contains(value))
+            .map_or(0, |&(_, depth)| depth)
+    }
+
+    /// Gets the maximum number of intervals that contain the same value.
+    ///
+    /// # Returns
+    ///
+    /// The maximum depth, or 0 if there are no intervals.
+    pub fn max_depth(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|&(_, depth)| depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Finds where the maximum depth is reached.
+    ///
+    /// # Returns
+    ///
+    /// The first segment with the maximum depth and its depth, or `None` if there are no
+    /// intervals.
+    pub fn max_depth_location(&self) -> Option<(Interval<T>, usize)> {
+        let max_depth = self.max_depth();
+        self.segments
+            .iter()
+            .find(|&&(_, depth)| depth == max_depth)
+            .copied()
+    }
+
+    /// Gets the values covered by at least `k` intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The minimum depth.
+    ///
+    /// # Returns
+    ///
+    /// An `IntervalSet` with the values whose depth is at least `k`.
+    pub fn covered_at_least(&self, k: usize) -> IntervalSet<T> {
+        IntervalSet::from_vec(
+            self.segments
+                .iter()
+                .filter(|&&(_, depth)| depth >= k)
+                .map(|&(segment, _)| segment)
+                .collect(),
+        )
+    }
+
+    /// Counts the values covered by at least `k` intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The minimum depth.
+    ///
+    /// # Returns
+    ///
+    /// The number of values whose depth is at least `k`, saturating to `u128::MAX` if it does
+    /// not fit.
+    pub fn count_covered_at_least(&self, k: usize) -> u128 {
+        self.segments
+            .iter()
+            .filter(|&&(_, depth)| depth >= k)
+            .map(|(segment, _)| segment.count())
+            .fold(0, u128::saturating_add)
+    }
+
+    /// Counts the values covered by any interval.
+    ///
+    /// # Returns
+    ///
+    /// The number of covered values, saturating to `u128::MAX` if it does not fit.
+    pub fn total_covered(&self) -> u128 {
+        self.count_covered_at_least(1)
+    }
+}
+
+impl<T: IntervalValue> FromIterator<Interval<T>> for Coverage<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::from_intervals(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
@@ -0,0 +1,137 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn example() -> Coverage<i32> {
+    Coverage::from_intervals(&[
+        Interval::from_boundaries(1, 10),
+        Interval::from_boundaries(5, 15),
+        Interval::from_boundaries(8, 9),
+        Interval::from_boundaries(20, 25),
+    ])
+}
+
+// Tests for from_intervals()
+
+#[test]
+fn from_intervals_empty() {
+    let coverage: Coverage<i32> = Coverage::from_intervals(&[]);
+    assert!(coverage.get_segments().is_empty());
+    assert_eq!(coverage.max_depth(), 0);
+    assert_eq!(coverage.max_depth_location(), None);
+    assert_eq!(coverage.total_covered(), 0);
+}
+
+#[test]
+fn from_intervals_splits_segments_by_depth() {
+    assert_eq!(
+        example().get_segments(),
+        &[
+            (Interval::from_boundaries(1, 4), 1),
+            (Interval::from_boundaries(5, 7), 2),
+            (Interval::from_boundaries(8, 9), 3),
+            (Interval::from_boundaries(10, 10), 2),
+            (Interval::from_boundaries(11, 15), 1),
+            (Interval::from_boundaries(20, 25), 1),
+        ]
+    );
+}
+
+#[test]
+fn from_intervals_merges_contiguous_segments_with_same_depth() {
+    let coverage: Coverage<i32> = [
+        Interval::from_boundaries(1, 3),
+        Interval::from_boundaries(4, 6),
+        Interval::from_boundaries(1, 6),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        coverage.get_segments(),
+        &[(Interval::from_boundaries(1, 6), 2)]
+    );
+}
+
+#[test]
+fn from_intervals_at_type_boundaries() {
+    let coverage = Coverage::from_intervals(&[
+        Interval::from_boundaries(250u8, u8::MAX),
+        Interval::from_boundaries(0, u8::MAX),
+    ]);
+    assert_eq!(
+        coverage.get_segments(),
+        &[
+            (Interval::from_boundaries(0, 249), 1),
+            (Interval::from_boundaries(250, u8::MAX), 2),
+        ]
+    );
+    assert_eq!(coverage.total_covered(), 256);
+}
+
+// Tests for depth_at()
+
+#[test]
+fn depth_at_values() {
+    let coverage = example();
+    assert_eq!(coverage.depth_at(0), 0);
+    assert_eq!(coverage.depth_at(1), 1);
+    assert_eq!(coverage.depth_at(8), 3);
+    assert_eq!(coverage.depth_at(10), 2);
+    assert_eq!(coverage.depth_at(17), 0);
+    assert_eq!(coverage.depth_at(25), 1);
+    assert_eq!(coverage.depth_at(26), 0);
+}
+
+// Tests for max_depth() and max_depth_location()
+
+#[test]
+fn max_depth_location_is_first_deepest_segment() {
+    let coverage = example();
+    assert_eq!(coverage.max_depth(), 3);
+    assert_eq!(
+        coverage.max_depth_location(),
+        Some((Interval::from_boundaries(8, 9), 3))
+    );
+}
+
+// Tests for covered_at_least() and count_covered_at_least()
+
+#[test]
+fn covered_at_least_k() {
+    let coverage = example();
+    assert_eq!(
+        coverage.covered_at_least(1).get(),
+        vec![
+            Interval::from_boundaries(1, 15),
+            Interval::from_boundaries(20, 25),
+        ]
+    );
+    assert_eq!(
+        coverage.covered_at_least(2).get(),
+        vec![Interval::from_boundaries(5, 10)]
+    );
+    assert!(coverage.covered_at_least(4).is_empty());
+    assert_eq!(coverage.total_covered(), 21);
+    assert_eq!(coverage.count_covered_at_least(2), 6);
+    assert_eq!(coverage.count_covered_at_least(3), 2);
+    assert_eq!(coverage.count_covered_at_least(4), 0);
+}
+
+#[test]
+fn count_covered_at_least_matches_brute_force() {
+    let intervals: Vec<Interval<i64>> = (0..50)
+        .map(|i| Interval::from_size((i * 31) % 97 - 20, (i * 7) % 23 + 1))
+        .collect();
+    let coverage = Coverage::from_intervals(&intervals);
+    for k in 0..6 {
+        let expected = (-30..120)
+            .filter(|&value| {
+                intervals
+                    .iter()
+                    .filter(|interval| interval.contains(value))
+                    .count()
+                    >= k.max(1)
+            })
+            .count() as u128;
+        assert_eq!(coverage.count_covered_at_least(k), expected, "k = {k}");
+    }
+}
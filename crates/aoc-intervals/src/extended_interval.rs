@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod extended_interval_tests;
+
+use crate::interval::Interval;
+use crate::interval_value::IntervalValue;
+use std::ops::{Bound, RangeBounds};
+
+/// Represents an interval of integer values that can extend without limit on either side.
+///
+/// Unlike [`Interval`], which always has a minimum and a maximum value, the sides of an
+/// `ExtendedInterval` can be unbounded, like the rays `a..` and `..=b` or the whole line `..`.
+/// The bounded sides are stored as included values, so `a..b` becomes `[a, b - 1]`.
+///
+/// It implements `RangeBounds`, so it can be used wherever Rust expects a range, like
+/// `BTreeMap::range`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of values in the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtendedInterval<T: IntervalValue> {
+    /// The first value of the interval, which is never `Bound::Excluded`.
+    start: Bound<T>,
+    /// The last value of the interval, which is never `Bound::Excluded`.
+    end: Bound<T>,
+}
+
+impl<T: IntervalValue> ExtendedInterval<T> {
+    /// Creates a new interval with all the values, unbounded on both sides.
+    pub fn whole() -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Creates a new ray with all the values greater than or equal to a value, unbounded on the
+    /// right.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The minimum value of the ray
+    pub fn at_least(min: T) -> Self {
+        Self {
+            start: Bound::Included(min),
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Creates a new ray with all the values less than or equal to a value, unbounded on the
+    /// left.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum value of the ray
+    pub fn at_most(max: T) -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Included(max),
+        }
+    }
+
+    /// Creates a new interval from any kind of Rust range, like `a..b`, `a..=b`, `a..`, `..b`,
+    /// `..=b` or `..`, keeping its unbounded sides.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values
+    ///
+    /// # Returns
+    ///
+    /// `Some(ExtendedInterval)` with the same values as the range, or `None` if the range is
+    /// empty
+    pub fn from_range<R: RangeBounds<T>>(range: R) -> Option<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Bound::Included(start),
+            Bound::Excluded(&start) => Bound::Included(start.checked_add(&T::one())?),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Bound::Included(end),
+            Bound::Excluded(&end) => Bound::Included(end.checked_sub(&T::one())?),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let is_empty =
+            matches!((start, end), (Bound::Included(min), Bound::Included(max)) if min > max);
+        (!is_empty).then_some(Self { start, end })
+    }
+
+    /// Returns the minimum value of the interval.
+    ///
+    /// # Returns
+    ///
+    /// `Some(min)`, or `None` if the interval is unbounded on the left
+    pub fn get_min(&self) -> Option<T> {
+        match self.start {
+            Bound::Included(min) => Some(min),
+            _ => None,
+        }
+    }
+
+    /// Returns the maximum value of the interval.
+    ///
+    /// # Returns
+    ///
+    /// `Some(max)`, or `None` if the interval is unbounded on the right
+    pub fn get_max(&self) -> Option<T> {
+        match self.end {
+            Bound::Included(max) => Some(max),
+            _ => None,
+        }
+    }
+
+    /// Returns both sides of the interval, which can be used to index slices like
+    /// `&items[interval.get_bounds()]`.
+    pub fn get_bounds(&self) -> (Bound<T>, Bound<T>) {
+        (self.start, self.end)
+    }
+
+    /// Checks if the interval has no minimum value.
+    pub fn is_unbounded_left(&self) -> bool {
+        self.start == Bound::Unbounded
+    }
+
+    /// Checks if the interval has no maximum value.
+    pub fn is_unbounded_right(&self) -> bool {
+        self.end == Bound::Unbounded
+    }
+
+    /// Checks if a value is contained within the interval.
+    pub fn contains(&self, value: T) -> bool {
+        RangeBounds::contains(self, &value)
+    }
+
+    /// Finds the intersection of this interval with another interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The interval to intersect with
+    ///
+    /// # Returns
+    ///
+    /// `Some(ExtendedInterval)` with the values present in both intervals, or `None` if they
+    /// don't overlap
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        // an unbounded side is farther than any value
+        let start = match (self.start, other.start) {
+            (Bound::Included(a), Bound::Included(b)) => Bound::Included(a.max(b)),
+            (Bound::Unbounded, bound) | (bound, Bound::Unbounded) => bound,
+            _ => unreachable!("The sides are never excluded"),
+        };
+        let end = match (self.end, other.end) {
+            (Bound::Included(a), Bound::Included(b)) => Bound::Included(a.min(b)),
+            (Bound::Unbounded, bound) | (bound, Bound::Unbounded) => bound,
+            _ => unreachable!("The sides are never excluded"),
+        };
+        Self::from_range((start, end))
+    }
+
+    /// Finds the values of a bounded interval that are also in this interval.
+    ///
+    /// Intersecting with `Interval::whole()` gives the values of this interval that fit in the
+    /// type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval to intersect with
+    ///
+    /// # Returns
+    ///
+    /// `Some(Interval)` with the values present in both intervals, or `None` if they don't
+    /// overlap
+    pub fn intersect_interval(&self, interval: &Interval<T>) -> Option<Interval<T>> {
+        self.intersect(&Self::from(*interval))?.to_interval()
+    }
+
+    /// Converts the interval into a bounded `Interval`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Interval)` with the same values, or `None` if any side is unbounded
+    pub fn to_interval(&self) -> Option<Interval<T>> {
+        Some(Interval::from_boundaries(self.get_min()?, self.get_max()?))
+    }
+}
+
+impl<T: IntervalValue> RangeBounds<T> for ExtendedInterval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+}
+
+impl<T: IntervalValue> From<Interval<T>> for ExtendedInterval<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self {
+            start: Bound::Included(interval.get_min()),
+            end: Bound::Included(interval.get_max()),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+use assertables::{assert_none, assert_some};
+use pretty_assertions::assert_eq;
+
+// Tests for whole, at_least and at_most
+
+#[test]
+fn whole_is_unbounded() {
+    let interval = ExtendedInterval::<u8>::whole();
+    assert!(interval.is_unbounded_left());
+    assert!(interval.is_unbounded_right());
+    assert_none!(interval.get_min());
+    assert_none!(interval.get_max());
+    assert!(interval.contains(0));
+    assert!(interval.contains(u8::MAX));
+}
+
+#[test]
+fn at_least_is_unbounded_right() {
+    let interval = ExtendedInterval::at_least(10u16);
+    assert_eq!(interval.get_min(), Some(10));
+    assert_none!(interval.get_max());
+    assert!(interval.is_unbounded_right());
+    assert!(!interval.is_unbounded_left());
+    assert!(interval.contains(u16::MAX));
+    assert!(!interval.contains(9));
+}
+
+#[test]
+fn at_most_is_unbounded_left() {
+    let interval = ExtendedInterval::at_most(-10i16);
+    assert_eq!(interval.get_max(), Some(-10));
+    assert!(interval.is_unbounded_left());
+    assert!(!interval.is_unbounded_right());
+    assert!(interval.contains(i16::MIN));
+    assert!(!interval.contains(-9));
+}
+
+#[test]
+fn bounded_at_type_extremes_is_not_unbounded() {
+    let interval = ExtendedInterval::from(Interval::from_boundaries(0u32, 5));
+    assert!(!interval.is_unbounded_left());
+    assert_ne!(interval, ExtendedInterval::at_most(5));
+    let interval = ExtendedInterval::from(Interval::<u8>::whole());
+    assert_ne!(interval, ExtendedInterval::whole());
+}
+
+// Tests for from_range
+
+#[test]
+fn from_range_all_kinds() {
+    assert_eq!(
+        ExtendedInterval::from_range(2..5),
+        Some(ExtendedInterval::from(Interval::from_boundaries(2, 4)))
+    );
+    assert_eq!(
+        ExtendedInterval::from_range(2..=5),
+        Some(ExtendedInterval::from(Interval::from_boundaries(2, 5)))
+    );
+    assert_eq!(
+        ExtendedInterval::from_range(2..),
+        Some(ExtendedInterval::at_least(2))
+    );
+    assert_eq!(
+        ExtendedInterval::from_range(..5),
+        Some(ExtendedInterval::at_most(4))
+    );
+    assert_eq!(
+        ExtendedInterval::from_range(..=5),
+        Some(ExtendedInterval::at_most(5))
+    );
+    assert_eq!(
+        ExtendedInterval::<i32>::from_range(..),
+        Some(ExtendedInterval::whole())
+    );
+    assert_eq!(
+        ExtendedInterval::from_range((Bound::Excluded(2), Bound::Unbounded)),
+        Some(ExtendedInterval::at_least(3))
+    );
+}
+
+#[test]
+fn from_range_empty() {
+    assert_none!(ExtendedInterval::from_range(5..5));
+    assert_none!(ExtendedInterval::from_range(..0u8));
+    assert_none!(ExtendedInterval::from_range((
+        Bound::Excluded(u8::MAX),
+        Bound::Unbounded
+    )));
+}
+
+// Tests for get_bounds
+
+#[test]
+fn get_bounds_indexes_slices() {
+    let items = [10, 20, 30, 40, 50];
+    assert_eq!(
+        &items[ExtendedInterval::at_least(3usize).get_bounds()],
+        &[40, 50]
+    );
+    assert_eq!(
+        &items[ExtendedInterval::from_range(1..3usize)
+            .unwrap()
+            .get_bounds()],
+        &[20, 30]
+    );
+    assert_eq!(&items[ExtendedInterval::whole().get_bounds()], &items);
+}
+
+// Tests for intersect and intersect_interval
+
+#[test]
+fn intersect_rays() {
+    let left = ExtendedInterval::at_most(10i64);
+    let right = ExtendedInterval::at_least(-5i64);
+    assert_eq!(
+        left.intersect(&right),
+        Some(ExtendedInterval::from(Interval::from_boundaries(-5, 10)))
+    );
+    assert_eq!(right.intersect(&ExtendedInterval::whole()), Some(right));
+    assert_none!(ExtendedInterval::at_most(0).intersect(&ExtendedInterval::at_least(1)));
+}
+
+#[test]
+fn intersect_interval_is_bounded() {
+    let ray = ExtendedInterval::at_least(250u8);
+    assert_eq!(
+        ray.intersect_interval(&Interval::whole()),
+        Some(Interval::from_boundaries(250, u8::MAX))
+    );
+    assert_eq!(
+        ray.intersect_interval(&Interval::from_boundaries(0, 252)),
+        Some(Interval::from_boundaries(250, 252))
+    );
+    assert_none!(ray.intersect_interval(&Interval::from_boundaries(0, 249)));
+}
+
+// Tests for to_interval
+
+#[test]
+fn to_interval() {
+    let interval = Interval::from_boundaries(-3, 3);
+    assert_eq!(
+        ExtendedInterval::from(interval).to_interval(),
+        Some(interval)
+    );
+    assert_none!(ExtendedInterval::at_least(3).to_interval());
+    assert_none!(ExtendedInterval::<i8>::whole().to_interval());
+    assert_some!(ExtendedInterval::from_range(0..=0u8).unwrap().to_interval());
+}
@@ -28,6 +28,42 @@ pub enum Boundary {
     End,
 }
 
+/// Error returned when converting an empty range into an interval, which always has at least
+/// one value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyRangeError;
+
+impl std::fmt::Display for EmptyRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "an interval cannot be created from an empty range")
+    }
+}
+
+impl std::error::Error for EmptyRangeError {}
+
+/// Error returned when parsing an interval from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntervalError {
+    /// The string does not follow any of the supported formats.
+    InvalidFormat(String),
+    /// One of the boundaries is not a valid value.
+    InvalidValue(String),
+    /// The string describes a half-open range without values.
+    EmptyRange,
+}
+
+impl std::fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat(input) => write!(f, "invalid interval format: '{input}'"),
+            Self::InvalidValue(input) => write!(f, "invalid interval value: '{input}'"),
+            Self::EmptyRange => write!(f, "an interval cannot be created from an empty range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
 /// Represents the relationship between two intervals.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Relationship {
@@ -66,28 +102,7 @@ impl<T: IntervalValue> Interval<T> {
     /// # Returns
     ///
     /// A new `Interval` spanning from `min(boundary1, boundary2)` to `max(boundary1, boundary2)`
-    ///
-    /// # Panics
-    ///
-    /// Panics if either boundary value is outside the valid interval range to prevent overflow in count calculations.
     pub fn from_boundaries(boundary1: T, boundary2: T) -> Self {
-        assert!(
-            boundary1 >= minimum_interval_value(),
-            "boundary1 cannot be less than the minimum interval value to prevent overflow"
-        );
-        assert!(
-            boundary2 >= minimum_interval_value(),
-            "boundary2 cannot be less than the minimum interval value to prevent overflow"
-        );
-        assert!(
-            boundary1 <= maximum_interval_value(),
-            "boundary1 cannot be greater than the maximum interval value"
-        );
-        assert!(
-            boundary2 <= maximum_interval_value(),
-            "boundary2 cannot be greater than the maximum interval value"
-        );
-
         Self {
             min: std::cmp::min(boundary1, boundary2),
             max: std::cmp::max(boundary1, boundary2),
@@ -109,17 +124,10 @@ impl<T: IntervalValue> Interval<T> {
     ///
     /// # Panics
     ///
-    /// Panics if `size` is not positive (greater than zero) or if the start value is outside the valid interval range to prevent overflow in count calculations.
+    /// Panics if `size` is not positive (greater than zero) or if the interval would exceed the
+    /// maximum value of the type.
     pub fn from_size(start: T, size: T) -> Self {
         assert!(size > T::zero(), "size must be positive");
-        assert!(
-            start >= minimum_interval_value(),
-            "start cannot be less than the minimum interval value to prevent overflow"
-        );
-        assert!(
-            start <= maximum_interval_value(),
-            "start cannot be greater than the maximum interval value"
-        );
 
         // Check for overflow in the addition before subtracting T::one()
         // We need to ensure start + size - 1 doesn't overflow
@@ -146,12 +154,13 @@ impl<T: IntervalValue> Interval<T> {
 
     /// Creates a new interval that spans the entire usable range of the type `T`.
     ///
-    /// The interval will span from `T::min_value() + 1` to `T::max_value()`, inclusive.
-    /// This excludes the minimum value to prevent overflow in count calculations.
+    /// The interval will span from `T::min_value()` to `T::max_value()`, inclusive. See
+    /// [`ExtendedInterval::whole`](crate::extended_interval::ExtendedInterval::whole) for an
+    /// interval without limits.
     ///
     /// # Returns
     ///
-    /// A new `Interval` spanning almost the complete range of type `T`
+    /// A new `Interval` spanning the complete range of type `T`
     pub fn whole() -> Self {
         Self {
             min: minimum_interval_value(),
@@ -159,26 +168,73 @@ impl<T: IntervalValue> Interval<T> {
         }
     }
 
+    /// Creates a new interval from a half-open range `[start, end)`, where the end value is
+    /// excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first value of the interval (included)
+    /// * `end` - The value after the last one of the interval (excluded)
+    ///
+    /// # Returns
+    ///
+    /// `Some(Interval)` spanning from `start` to `end - 1`, or `None` if the range is empty
+    pub fn from_half_open(start: T, end: T) -> Option<Self> {
+        (start < end).then(|| Self {
+            min: start,
+            max: end - T::one(),
+        })
+    }
+
+    /// Creates a new interval from any kind of Rust range, like `a..b`, `a..=b`, `a..`, `..b`,
+    /// `..=b` or `..`.
+    ///
+    /// Unbounded sides are clamped to the limits of the type `T`. Use
+    /// [`ExtendedInterval`](crate::extended_interval::ExtendedInterval) to keep them unbounded.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values
+    ///
+    /// # Returns
+    ///
+    /// `Some(Interval)` with the same values as the range, or `None` if the range is empty
+    pub fn from_range<R: std::ops::RangeBounds<T>>(range: R) -> Option<Self> {
+        use std::ops::Bound;
+        let min = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(&T::one())?,
+            Bound::Unbounded => minimum_interval_value(),
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => end.checked_sub(&T::one())?,
+            Bound::Unbounded => maximum_interval_value(),
+        };
+        (min <= max).then_some(Self { min, max })
+    }
+
     /// Returns number of contained items in the interval.
     ///
     /// The size is calculated as `max - min + 1`, representing the number of
     /// integer values contained in the interval.
     ///
+    /// The only count that does not fit in a `u128` is the one of the whole range of a 128-bit
+    /// type, which saturates to `u128::MAX`.
+    ///
     /// # Returns
     ///
     /// The number of values contained in the interval
-    pub fn count(&self) -> u64 {
-        // Use wider integer arithmetic to prevent overflow
-        // Convert to i128 for the calculation, then back to u64
-        let min_wide: i128 = cast(self.min).unwrap_or(0);
-        let max_wide: i128 = cast(self.max).unwrap_or(0);
-        let size_wide = max_wide - min_wide + 1;
-
-        // Clamp to u64::MAX if the result is too large
-        if size_wide > (u64::MAX as i128) {
-            u64::MAX
+    pub fn count(&self) -> u128 {
+        if self.min >= T::zero() || self.max < T::zero() {
+            // both boundaries have the same sign, so their difference cannot overflow
+            let distance: u128 = cast(self.max - self.min).unwrap();
+            distance.saturating_add(1)
         } else {
-            size_wide as u64
+            // count the negative and the non-negative values separately
+            let negatives: u128 = cast(T::zero() - (self.min + T::one())).unwrap();
+            let non_negatives: u128 = cast(self.max).unwrap();
+            negatives.saturating_add(non_negatives).saturating_add(2)
         }
     }
 
@@ -209,6 +265,35 @@ impl<T: IntervalValue> Interval<T> {
         (self.min, self.max)
     }
 
+    /// Returns the value after the maximum value of the interval, which is the end of the
+    /// equivalent half-open range.
+    ///
+    /// # Returns
+    ///
+    /// `Some(max + 1)`, or `None` if the maximum value is `T::max_value()`
+    pub fn get_end_exclusive(&self) -> Option<T> {
+        self.max.checked_add(&T::one())
+    }
+
+    /// Converts the interval into a half-open range `min..max + 1`, which can be used to index
+    /// slices.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Range)` with the same values, or `None` if the maximum value is `T::max_value()`
+    pub fn to_range(&self) -> Option<std::ops::Range<T>> {
+        Some(self.min..self.get_end_exclusive()?)
+    }
+
+    /// Converts the interval into an inclusive range `min..=max`.
+    ///
+    /// # Returns
+    ///
+    /// A `RangeInclusive` with the same values
+    pub fn to_range_inclusive(&self) -> std::ops::RangeInclusive<T> {
+        self.min..=self.max
+    }
+
     /// Checks if a value is contained within the interval.
     ///
     /// Returns `true` if the value is between the minimum and maximum boundaries,
@@ -446,7 +531,8 @@ impl<T: IntervalValue> Interval<T> {
     ///
     /// `true` if the intervals are adjacent, `false` otherwise
     pub fn is_contiguous_to(&self, other: &Self) -> bool {
-        self.max + T::one() == other.min || other.max + T::one() == self.min
+        self.max.checked_add(&T::one()) == Some(other.min)
+            || other.max.checked_add(&T::one()) == Some(self.min)
     }
 
     /// Determines the location of a value relative to this interval.
@@ -487,18 +573,15 @@ impl<T: IntervalValue> Interval<T> {
     /// # Returns
     ///
     /// A new `Interval` shifted by the specified offset
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the shifted boundaries is out of the range of the type.
     pub fn shift(&self, offset: i32) -> Self {
-        let offset: T = cast(offset).expect("Failed to convert offset to type T");
-        let new_min = self.min + offset;
-        let new_max = self.max + offset;
-        assert!(
-            new_min >= minimum_interval_value() && new_min <= maximum_interval_value(),
-            "Shifted minimum value is out of bounds"
-        );
-        assert!(
-            new_max >= minimum_interval_value() && new_max <= maximum_interval_value(),
-            "Shifted maximum value is out of bounds"
-        );
+        let new_min =
+            add_offset(self.min, offset.into()).expect("Shifted minimum value is out of bounds");
+        let new_max =
+            add_offset(self.max, offset.into()).expect("Shifted maximum value is out of bounds");
         Interval {
             min: new_min,
             max: new_max,
@@ -517,23 +600,13 @@ impl<T: IntervalValue> Interval<T> {
     /// # Returns
     ///
     /// A new `Interval` expanded by the specified offset on both sides
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval contracts to nothing or if any of the new boundaries is out of the
+    /// range of the type.
     pub fn expand_equally(&self, offset: i32) -> Self {
-        let offset: T = cast(offset).expect("Failed to convert offset to type T");
-        let new_min = self.min - offset;
-        let new_max = self.max + offset;
-        assert!(new_min <= new_max);
-        assert!(
-            new_min >= minimum_interval_value() && new_min <= maximum_interval_value(),
-            "Shifted minimum value is out of bounds"
-        );
-        assert!(
-            new_max >= minimum_interval_value() && new_max <= maximum_interval_value(),
-            "Shifted maximum value is out of bounds"
-        );
-        Interval {
-            min: new_min,
-            max: new_max,
-        }
+        self.expand(offset, offset)
     }
 
     /// Creates a new interval by expanding this interval with different offsets for each side.
@@ -549,20 +622,17 @@ impl<T: IntervalValue> Interval<T> {
     /// # Returns
     ///
     /// A new `Interval` expanded by the specified offsets
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval contracts to nothing or if any of the new boundaries is out of the
+    /// range of the type.
     pub fn expand(&self, left_offset: i32, right_offset: i32) -> Self {
-        let left_offset: T = cast(left_offset).expect("Failed to convert left offset to type T");
-        let right_offset: T = cast(right_offset).expect("Failed to convert right offset to type T");
-        let new_min = self.min - left_offset;
-        let new_max = self.max + right_offset;
+        let new_min = add_offset(self.min, -i64::from(left_offset))
+            .expect("Shifted minimum value is out of bounds");
+        let new_max = add_offset(self.max, right_offset.into())
+            .expect("Shifted maximum value is out of bounds");
         assert!(new_min <= new_max);
-        assert!(
-            new_min >= minimum_interval_value() && new_min <= maximum_interval_value(),
-            "Shifted minimum value is out of bounds"
-        );
-        assert!(
-            new_max >= minimum_interval_value() && new_max <= maximum_interval_value(),
-            "Shifted maximum value is out of bounds"
-        );
         Interval {
             min: new_min,
             max: new_max,
@@ -585,6 +655,11 @@ impl<T: IntervalValue> Interval<T> {
     /// A value of the same type representing the relative position. A positive result
     /// indicates that the `value` is greater than the boundary, while a negative result
     /// means it is smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented in `T`, like a negative position for an
+    /// unsigned type.
     pub fn get_relative_position_from(&self, boundary: Boundary, value: T) -> T {
         match boundary {
             Boundary::Start => value - self.min,
@@ -593,12 +668,91 @@ impl<T: IntervalValue> Interval<T> {
     }
 }
 
+/// Adds a signed offset to a value.
+///
+/// # Returns
+///
+/// The resulting value, or `None` if it is out of the range of the type.
+fn add_offset<T: IntervalValue>(value: T, offset: i64) -> Option<T> {
+    let magnitude: T = cast(offset.unsigned_abs())?;
+    if offset >= 0 {
+        value.checked_add(&magnitude)
+    } else {
+        value.checked_sub(&magnitude)
+    }
+}
+
 impl<T: IntervalValue> std::fmt::Display for Interval<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[{}, {}]", self.min, self.max)
     }
 }
 
+/// Parses an interval from a string.
+///
+/// The supported formats are `a-b` and `a..=b` (both boundaries included), `a..b` (the end value
+/// excluded) and `[a, b]`, which is the format produced by `Display`. Whitespace around the values
+/// is ignored, and the boundaries can be in any order, except in `a..b`.
+impl<T: IntervalValue + std::str::FromStr> std::str::FromStr for Interval<T> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_value = |value: &str| {
+            value
+                .trim()
+                .parse::<T>()
+                .map_err(|_| ParseIntervalError::InvalidValue(value.trim().to_string()))
+        };
+        if let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let (start, end) = inner
+                .split_once(',')
+                .ok_or_else(|| ParseIntervalError::InvalidFormat(s.to_string()))?;
+            Ok(Self::from_boundaries(
+                parse_value(start)?,
+                parse_value(end)?,
+            ))
+        } else if let Some((start, end)) = s.split_once("..=") {
+            Ok(Self::from_boundaries(
+                parse_value(start)?,
+                parse_value(end)?,
+            ))
+        } else if let Some((start, end)) = s.split_once("..") {
+            Self::from_half_open(parse_value(start)?, parse_value(end)?)
+                .ok_or(ParseIntervalError::EmptyRange)
+        } else {
+            // the first character can be the sign of the first value
+            let separator = s
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '-')
+                .map(|(index, _)| index)
+                .ok_or_else(|| ParseIntervalError::InvalidFormat(s.to_string()))?;
+            Ok(Self::from_boundaries(
+                parse_value(&s[..separator])?,
+                parse_value(&s[separator + 1..])?,
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: IntervalValue + serde::Serialize> serde::Serialize for Interval<T> {
+    /// Serializes the interval as a pair `(min, max)`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get_boundaries().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: IntervalValue + serde::Deserialize<'de>> serde::Deserialize<'de> for Interval<T> {
+    /// Deserializes the interval from a pair of boundaries, in any order.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (boundary1, boundary2) = <(T, T)>::deserialize(deserializer)?;
+        Ok(Self::from_boundaries(boundary1, boundary2))
+    }
+}
+
 /// Left shift operator implementation for Interval.
 ///
 /// Shifts both the minimum and maximum values to the left (decreases them)
@@ -635,31 +789,51 @@ impl<T: IntervalValue> Ord for Interval<T> {
     }
 }
 
+impl<T: IntervalValue> From<Interval<T>> for std::ops::RangeInclusive<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.to_range_inclusive()
+    }
+}
+
+impl<T: IntervalValue> TryFrom<std::ops::Range<T>> for Interval<T> {
+    type Error = EmptyRangeError;
+
+    fn try_from(range: std::ops::Range<T>) -> Result<Self, Self::Error> {
+        Self::from_range(range).ok_or(EmptyRangeError)
+    }
+}
+
+impl<T: IntervalValue> TryFrom<std::ops::RangeInclusive<T>> for Interval<T> {
+    type Error = EmptyRangeError;
+
+    fn try_from(range: std::ops::RangeInclusive<T>) -> Result<Self, Self::Error> {
+        Self::from_range(range).ok_or(EmptyRangeError)
+    }
+}
+
 impl<T: IntervalValue> IntoIterator for Interval<T> {
     type Item = T;
     type IntoIter = IntervalIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntervalIter {
-            current: self.min,
+            current: Some(self.min),
             end: self.max,
         }
     }
 }
 
 pub struct IntervalIter<T: IntervalValue> {
-    current: T,
+    /// The next value to return, or `None` once the end has been reached.
+    current: Option<T>,
     end: T,
 }
 
 impl<T: IntervalValue> Iterator for IntervalIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<T> {
-        if self.current > self.end {
-            return None;
-        }
-        let val = self.current;
-        self.current = self.current + T::one();
+        let val = self.current?;
+        self.current = (val < self.end).then(|| val + T::one());
         Some(val)
     }
 }
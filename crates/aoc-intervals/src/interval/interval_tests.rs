@@ -205,51 +205,26 @@ fn from_boundaries_minimum_and_maximum_valid_values_i64_type() {
 }
 
 #[test]
-#[should_panic(
-    expected = "boundary1 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_boundary1_too_small_i32() {
-    let _interval: Interval<i32> = Interval::from_boundaries(i32::MIN, 10);
+fn from_boundaries_type_minimum_i32() {
+    let interval: Interval<i32> = Interval::from_boundaries(10, i32::MIN);
+    assert_eq!(interval.get_boundaries(), (i32::MIN, 10));
+    assert_eq!(interval.count(), 2_147_483_659);
 }
 
 #[test]
-#[should_panic(
-    expected = "boundary2 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_boundary2_too_small_i32() {
-    let _interval: Interval<i32> = Interval::from_boundaries(10, i32::MIN);
-}
-
-#[test]
-#[should_panic(
-    expected = "boundary1 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_both_boundaries_too_small_i32() {
-    let _interval: Interval<i32> = Interval::from_boundaries(i32::MIN, i32::MIN);
-}
-
-#[test]
-#[should_panic(
-    expected = "boundary1 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_boundary1_too_small_i64() {
-    let _interval: Interval<i64> = Interval::from_boundaries(i64::MIN, 100);
-}
-
-#[test]
-#[should_panic(
-    expected = "boundary2 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_boundary2_too_small_i64() {
-    let _interval: Interval<i64> = Interval::from_boundaries(100, i64::MIN);
+fn from_boundaries_type_minimum_i64() {
+    let interval: Interval<i64> = Interval::from_boundaries(i64::MIN, i64::MIN);
+    assert_eq!(interval.get_boundaries(), (i64::MIN, i64::MIN));
+    assert_eq!(interval.count(), 1);
 }
 
 #[test]
-#[should_panic(
-    expected = "boundary1 cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_boundaries_both_boundaries_too_small_i64() {
-    let _interval: Interval<i64> = Interval::from_boundaries(i64::MIN, i64::MIN);
+fn from_boundaries_unsigned_types() {
+    let interval: Interval<u32> = Interval::from_boundaries(u32::MAX, 0);
+    assert_eq!(interval.get_boundaries(), (0, u32::MAX));
+    assert_eq!(interval.count(), 4_294_967_296);
+    let interval: Interval<usize> = Interval::from_boundaries(3, 7);
+    assert_eq!(interval.count(), 5);
 }
 
 // Tests for from_size
@@ -355,11 +330,11 @@ fn from_size_maximum_valid_start() {
 }
 
 #[test]
-#[should_panic(
-    expected = "start cannot be less than the minimum interval value to prevent overflow"
-)]
-fn from_size_invalid_start_too_small() {
-    let _interval: Interval<i32> = Interval::from_size(i32::MIN, 5);
+fn from_size_type_minimum_start() {
+    let interval: Interval<i32> = Interval::from_size(i32::MIN, 5);
+    assert_eq!(interval.get_boundaries(), (i32::MIN, i32::MIN + 4));
+    let interval: Interval<u64> = Interval::from_size(0, 5);
+    assert_eq!(interval.get_boundaries(), (0, 4));
 }
 
 #[test]
@@ -385,25 +360,103 @@ fn from_size_negative_size() {
 #[test]
 fn whole_i32() {
     let interval: Interval<i32> = Interval::whole();
-    assert_eq!(interval.min, i32::MIN + 1);
-    assert_eq!(interval.max, i32::MAX);
-    let count = (i32::MAX as i128) - (i32::MIN as i128);
-    assert_eq!(interval.count(), count as u64);
-    assert_eq!(interval.get_min(), i32::MIN + 1);
-    assert_eq!(interval.get_max(), i32::MAX);
-    assert_eq!(interval.get_boundaries(), (i32::MIN + 1, i32::MAX));
+    assert_eq!(interval.get_boundaries(), (i32::MIN, i32::MAX));
+    assert_eq!(interval.count(), 1 << 32);
 }
 
 #[test]
 fn whole_i64() {
     let interval: Interval<i64> = Interval::whole();
-    assert_eq!(interval.min, i64::MIN + 1);
-    assert_eq!(interval.max, i64::MAX);
-    let count = (i64::MAX as i128) - (i64::MIN as i128);
-    assert_eq!(interval.count(), count as u64);
-    assert_eq!(interval.get_min(), i64::MIN + 1);
-    assert_eq!(interval.get_max(), i64::MAX);
-    assert_eq!(interval.get_boundaries(), (i64::MIN + 1, i64::MAX));
+    assert_eq!(interval.get_boundaries(), (i64::MIN, i64::MAX));
+    assert_eq!(interval.count(), 1 << 64);
+}
+
+#[test]
+fn whole_u64() {
+    let interval: Interval<u64> = Interval::whole();
+    assert_eq!(interval.get_boundaries(), (0, u64::MAX));
+    assert_eq!(interval.count(), 1 << 64);
+}
+
+#[test]
+fn whole_128_bit_types_saturate_count() {
+    assert_eq!(Interval::<i128>::whole().count(), u128::MAX);
+    assert_eq!(Interval::<u128>::whole().count(), u128::MAX);
+    assert_eq!(
+        Interval::from_boundaries(i128::MIN + 1, i128::MAX).count(),
+        u128::MAX
+    );
+    assert_eq!(Interval::from_boundaries(1, u128::MAX).count(), u128::MAX);
+}
+
+// Tests for from_half_open
+
+#[test]
+fn from_half_open_excludes_end() {
+    assert_eq!(
+        Interval::from_half_open(3, 7),
+        Some(Interval::from_boundaries(3, 6))
+    );
+    assert_eq!(
+        Interval::from_half_open(3, 4),
+        Some(Interval::from_boundaries(3, 3))
+    );
+}
+
+#[test]
+fn from_half_open_empty() {
+    assert_none!(Interval::from_half_open(3, 3));
+    assert_none!(Interval::from_half_open(7, 3));
+    assert_none!(Interval::from_half_open(0u32, 0));
+}
+
+// Tests for from_range
+
+#[test]
+fn from_range_all_kinds_of_ranges() {
+    assert_eq!(
+        Interval::from_range(2..5),
+        Some(Interval::from_boundaries(2, 4))
+    );
+    assert_eq!(
+        Interval::from_range(2..=5),
+        Some(Interval::from_boundaries(2, 5))
+    );
+    assert_eq!(
+        Interval::from_range(250u8..),
+        Some(Interval::from_boundaries(250, u8::MAX))
+    );
+    assert_eq!(
+        Interval::from_range(..3u8),
+        Some(Interval::from_boundaries(0, 2))
+    );
+    assert_eq!(
+        Interval::from_range(..=3i8),
+        Some(Interval::from_boundaries(i8::MIN, 3))
+    );
+    assert_eq!(Interval::<i64>::from_range(..), Some(Interval::whole()));
+}
+
+#[test]
+fn from_range_excluded_start() {
+    use std::ops::Bound;
+    assert_eq!(
+        Interval::from_range((Bound::Excluded(2), Bound::Included(5))),
+        Some(Interval::from_boundaries(3, 5))
+    );
+    assert_none!(Interval::from_range((
+        Bound::Excluded(u8::MAX),
+        Bound::Unbounded
+    )));
+}
+
+#[test]
+fn from_range_empty() {
+    assert_none!(Interval::from_range(5..5));
+    assert_none!(Interval::from_range(..0u32));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 5..=2;
+    assert_none!(Interval::from_range(reversed));
 }
 
 // Tests for contains
@@ -694,6 +747,15 @@ fn is_contiguous_to_overlapping() {
     assert!(!interval2.is_contiguous_to(&interval1));
 }
 
+#[test]
+fn is_contiguous_to_at_type_boundaries() {
+    let upper: Interval<u8> = Interval::from_boundaries(200, u8::MAX);
+    let lower: Interval<u8> = Interval::from_boundaries(0, 199);
+    assert!(upper.is_contiguous_to(&lower));
+    assert!(lower.is_contiguous_to(&upper));
+    assert!(!upper.is_contiguous_to(&Interval::from_boundaries(0, 10)));
+}
+
 // Tests for get_location
 
 #[test]
@@ -767,6 +829,20 @@ fn shift_negative_interval_negative_offset() {
     assert_eq!(shifted.get_boundaries(), (-25, -15));
 }
 
+#[test]
+fn shift_unsigned_interval() {
+    let interval: Interval<u32> = Interval::from_boundaries(10, 20);
+    assert_eq!(interval.shift(-10), Interval::from_boundaries(0, 10));
+    assert_eq!(interval.shift(5), Interval::from_boundaries(15, 25));
+}
+
+#[test]
+#[should_panic(expected = "Shifted minimum value is out of bounds")]
+fn shift_unsigned_interval_below_zero() {
+    let interval: Interval<u32> = Interval::from_boundaries(10, 20);
+    let _shifted = interval.shift(-11);
+}
+
 // Tests for expand_equally
 
 #[test]
@@ -855,6 +931,13 @@ fn expand_wrong_offset() {
     let _expanded = interval.expand(-10, -10);
 }
 
+#[test]
+fn expand_unsigned_interval() {
+    let interval: Interval<u64> = Interval::from_boundaries(10, 20);
+    assert_eq!(interval.expand(10, -5), Interval::from_boundaries(0, 15));
+    assert_eq!(interval.expand(-5, 1), Interval::from_boundaries(15, 21));
+}
+
 // Tests for difference
 
 #[test]
@@ -1340,3 +1423,133 @@ fn iterator_single_value() {
     let values: Vec<_> = interval.into_iter().collect();
     assert_eq!(values, vec![5]);
 }
+
+#[test]
+fn iterator_reaches_type_maximum() {
+    let values: Vec<u8> = Interval::from_boundaries(253, u8::MAX)
+        .into_iter()
+        .collect();
+    assert_eq!(values, vec![253, 254, 255]);
+    let values: Vec<i8> = Interval::from_boundaries(i8::MAX, i8::MAX)
+        .into_iter()
+        .collect();
+    assert_eq!(values, vec![i8::MAX]);
+}
+
+// Tests for range conversions
+
+#[test]
+fn get_end_exclusive() {
+    assert_eq!(Interval::from_boundaries(2, 5).get_end_exclusive(), Some(6));
+    assert_none!(Interval::from_boundaries(5u8, u8::MAX).get_end_exclusive());
+}
+
+#[test]
+fn to_range() {
+    assert_eq!(Interval::from_boundaries(2, 5).to_range(), Some(2..6));
+    assert_none!(Interval::<i32>::whole().to_range());
+    let values = [10, 20, 30, 40, 50];
+    let interval: Interval<usize> = Interval::from_boundaries(1, 3);
+    assert_eq!(values[interval.to_range().unwrap()], [20, 30, 40]);
+}
+
+#[test]
+fn to_range_inclusive() {
+    let interval = Interval::from_boundaries(-2, 2);
+    assert_eq!(interval.to_range_inclusive(), -2..=2);
+    let range: std::ops::RangeInclusive<i32> = interval.into();
+    assert_eq!(range.sum::<i32>(), 0);
+    let values = [10, 20, 30, 40, 50];
+    assert_eq!(
+        values[Interval::from_boundaries(3usize, 4).to_range_inclusive()],
+        [40, 50]
+    );
+}
+
+#[test]
+fn try_from_ranges() {
+    assert_eq!(
+        Interval::try_from(2..5),
+        Ok(Interval::from_boundaries(2, 4))
+    );
+    assert_eq!(
+        Interval::try_from(2..=5),
+        Ok(Interval::from_boundaries(2, 5))
+    );
+    assert_eq!(Interval::try_from(5..5), Err(EmptyRangeError));
+    assert_eq!(
+        EmptyRangeError.to_string(),
+        "an interval cannot be created from an empty range"
+    );
+}
+
+// Tests for FromStr trait
+
+#[test]
+fn from_str_dash_separated() {
+    assert_eq!("2-4".parse(), Ok(Interval::from_boundaries(2, 4)));
+    assert_eq!(" 6 - 8 ".parse(), Ok(Interval::from_boundaries(6, 8)));
+    assert_eq!("-5--3".parse(), Ok(Interval::from_boundaries(-5, -3)));
+    assert_eq!("-5-3".parse(), Ok(Interval::from_boundaries(-5, 3)));
+    assert_eq!("9-1".parse(), Ok(Interval::from_boundaries(1, 9)));
+}
+
+#[test]
+fn from_str_rust_ranges() {
+    assert_eq!("2..=4".parse(), Ok(Interval::from_boundaries(2, 4)));
+    assert_eq!("2..4".parse(), Ok(Interval::from_boundaries(2, 3)));
+    assert_eq!("-3..-1".parse(), Ok(Interval::from_boundaries(-3, -2)));
+    assert_eq!(
+        "4..4".parse::<Interval<i32>>(),
+        Err(ParseIntervalError::EmptyRange)
+    );
+}
+
+#[test]
+fn from_str_display_format() {
+    assert_eq!("[-7, 12]".parse(), Ok(Interval::from_boundaries(-7, 12)));
+    let interval: Interval<u64> = Interval::from_boundaries(0, u64::MAX);
+    assert_eq!(interval.to_string().parse(), Ok(interval));
+}
+
+#[test]
+fn from_str_errors() {
+    assert_eq!(
+        "5".parse::<Interval<i32>>(),
+        Err(ParseIntervalError::InvalidFormat("5".to_string()))
+    );
+    assert_eq!(
+        "[1 2]".parse::<Interval<i32>>(),
+        Err(ParseIntervalError::InvalidFormat("[1 2]".to_string()))
+    );
+    assert_eq!(
+        "1-x".parse::<Interval<i32>>(),
+        Err(ParseIntervalError::InvalidValue("x".to_string()))
+    );
+    assert_eq!(
+        "-1-3".parse::<Interval<u32>>(),
+        Err(ParseIntervalError::InvalidValue("-1".to_string()))
+    );
+    assert_eq!(
+        ParseIntervalError::InvalidValue("x".to_string()).to_string(),
+        "invalid interval value: 'x'"
+    );
+}
+
+// Tests for serde support
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let interval = Interval::from_boundaries(-3, 8);
+    let json = serde_json::to_string(&interval).unwrap();
+    assert_eq!(json, "[-3,8]");
+    assert_eq!(
+        serde_json::from_str::<Interval<i32>>(&json).unwrap(),
+        interval
+    );
+    assert_eq!(
+        serde_json::from_str::<Interval<i32>>("[8,-3]").unwrap(),
+        interval
+    );
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod interval_box_tests;
+
+use crate::interval::Interval;
+use crate::interval_value::IntervalValue;
+
+/// An axis-aligned box of integer points in N-dimensional space, defined by one interval per
+/// axis. Both boundary values of every interval are included.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of values in the intervals.
+/// * `N` - The number of dimensions (compile-time constant).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalBox<T: IntervalValue, const N: usize> {
+    /// The values covered in each axis.
+    intervals: [Interval<T>; N],
+}
+
+impl<T: IntervalValue, const N: usize> IntervalBox<T, N> {
+    /// Creates a new box from the intervals of every axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `intervals` - The values covered in each axis
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalBox` covering all the combinations of values of the intervals
+    pub fn new(intervals: [Interval<T>; N]) -> Self {
+        Self { intervals }
+    }
+
+    /// Returns the intervals of all the axes.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the array of intervals, one per axis
+    pub fn get_intervals(&self) -> &[Interval<T>; N] {
+        &self.intervals
+    }
+
+    /// Returns the interval of a single axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The index of the axis (0-based)
+    ///
+    /// # Returns
+    ///
+    /// A reference to the interval of the axis
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis index is out of bounds.
+    pub fn get(&self, axis: usize) -> &Interval<T> {
+        assert!(axis < N, "Axis index out of bounds");
+        &self.intervals[axis]
+    }
+
+    /// Creates a copy of this box with a different interval in one axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The index of the axis (0-based)
+    /// * `interval` - The new interval of the axis
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalBox` with the interval of the axis replaced
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis index is out of bounds.
+    pub fn with_interval(&self, axis: usize, interval: Interval<T>) -> Self {
+        assert!(axis < N, "Axis index out of bounds");
+        let mut intervals = self.intervals;
+        intervals[axis] = interval;
+        Self { intervals }
+    }
+
+    /// Returns number of contained points in the box.
+    ///
+    /// # Returns
+    ///
+    /// The product of the number of values of every interval
+    pub fn count(&self) -> u128 {
+        self.intervals.iter().map(Interval::count).product()
+    }
+
+    /// Checks if a point is contained within the box.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The value of the point in every axis
+    ///
+    /// # Returns
+    ///
+    /// `true` if every value is contained in the interval of its axis, `false` otherwise
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        (0..N).all(|axis| self.intervals[axis].contains(point[axis]))
+    }
+
+    /// Checks if this box completely contains another box.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The box to check against
+    ///
+    /// # Returns
+    ///
+    /// `true` if every interval of this box subsumes the interval of the other box in the same
+    /// axis, `false` otherwise
+    pub fn subsumes(&self, other: &Self) -> bool {
+        (0..N).all(|axis| self.intervals[axis].subsumes(&other.intervals[axis]))
+    }
+
+    /// Splits the box in two parts along an axis: the values lower than a threshold, and the
+    /// rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The index of the axis (0-based)
+    /// * `threshold` - The lowest value of the upper part
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(lower, upper)`, where `lower` contains the points whose value in the axis is
+    /// less than `threshold`, and `upper` the rest. Any of them is `None` if it would be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis index is out of bounds.
+    pub fn split_at(&self, axis: usize, threshold: T) -> (Option<Self>, Option<Self>) {
+        let (min, max) = self.get(axis).get_boundaries();
+        if threshold <= min {
+            (None, Some(*self))
+        } else if threshold > max {
+            (Some(*self), None)
+        } else {
+            (
+                Some(
+                    self.with_interval(axis, Interval::from_boundaries(min, threshold - T::one())),
+                ),
+                Some(self.with_interval(axis, Interval::from_boundaries(threshold, max))),
+            )
+        }
+    }
+
+    /// Splits the box in two parts along an axis: the values less than a threshold, and the
+    /// rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The index of the axis (0-based)
+    /// * `threshold` - The value to compare with
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(matching, remaining)`, where `matching` contains the points whose value in the
+    /// axis is less than `threshold`. Any of them is `None` if it would be empty.
+    pub fn split_less_than(&self, axis: usize, threshold: T) -> (Option<Self>, Option<Self>) {
+        self.split_at(axis, threshold)
+    }
+
+    /// Splits the box in two parts along an axis: the values greater than a threshold, and the
+    /// rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The index of the axis (0-based)
+    /// * `threshold` - The value to compare with
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(matching, remaining)`, where `matching` contains the points whose value in the
+    /// axis is greater than `threshold`. Any of them is `None` if it would be empty.
+    pub fn split_greater_than(&self, axis: usize, threshold: T) -> (Option<Self>, Option<Self>) {
+        if threshold >= self.get(axis).get_max() {
+            return (None, Some(*self));
+        }
+        let (lower, upper) = self.split_at(axis, threshold + T::one());
+        (upper, lower)
+    }
+
+    /// Finds the intersection of this box with another box.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The box to intersect with
+    ///
+    /// # Returns
+    ///
+    /// `Some(IntervalBox)` containing the points present in both boxes, `None` if they don't
+    /// overlap
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let mut intervals = self.intervals;
+        for (axis, interval) in intervals.iter_mut().enumerate() {
+            *interval = interval.intersect(&other.intervals[axis])?;
+        }
+        Some(Self { intervals })
+    }
+
+    /// Removes the points of another box from this box.
+    ///
+    /// The remaining points are split, axis by axis, into the slabs below and above the removed
+    /// part, so the result has at most `2 * N` boxes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The box to remove
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<IntervalBox>` of disjoint boxes that contain exactly the points of this box which
+    /// are not in the other box
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersect(other) else {
+            return vec![*self];
+        };
+        let mut results = Vec::with_capacity(2 * N);
+        let mut remaining = *self;
+        for axis in 0..N {
+            let (min, max) = remaining.intervals[axis].get_boundaries();
+            let (common_min, common_max) = common.intervals[axis].get_boundaries();
+            if min < common_min {
+                results.push(
+                    remaining
+                        .with_interval(axis, Interval::from_boundaries(min, common_min - T::one())),
+                );
+            }
+            if common_max < max {
+                results.push(
+                    remaining
+                        .with_interval(axis, Interval::from_boundaries(common_max + T::one(), max)),
+                );
+            }
+            remaining = remaining.with_interval(axis, common.intervals[axis]);
+        }
+        results
+    }
+}
+
+impl<T: IntervalValue, const N: usize> std::fmt::Display for IntervalBox<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(|i| i.to_string()).collect();
+        write!(f, "{}", intervals.join(" x "))
+    }
+}
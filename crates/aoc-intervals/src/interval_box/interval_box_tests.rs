@@ -0,0 +1,191 @@
+use super::*;
+use assertables::{assert_none, assert_some};
+use pretty_assertions::assert_eq;
+
+fn square(x: (i32, i32), y: (i32, i32)) -> IntervalBox<i32, 2> {
+    IntervalBox::new([
+        Interval::from_boundaries(x.0, x.1),
+        Interval::from_boundaries(y.0, y.1),
+    ])
+}
+
+// Tests for new
+
+#[test]
+fn new_and_getters() {
+    let interval_box = square((1, 4), (-2, 2));
+    assert_eq!(interval_box.get(0), &Interval::from_boundaries(1, 4));
+    assert_eq!(interval_box.get(1), &Interval::from_boundaries(-2, 2));
+    assert_eq!(
+        interval_box.get_intervals(),
+        &[
+            Interval::from_boundaries(1, 4),
+            Interval::from_boundaries(-2, 2)
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Axis index out of bounds")]
+fn get_out_of_bounds_panics() {
+    square((1, 4), (-2, 2)).get(2);
+}
+
+#[test]
+fn with_interval() {
+    let interval_box = square((1, 4), (-2, 2)).with_interval(1, Interval::from_boundaries(7, 9));
+    assert_eq!(interval_box, square((1, 4), (7, 9)));
+}
+
+// Tests for count
+
+#[test]
+fn count() {
+    assert_eq!(square((1, 4), (-2, 2)).count(), 20);
+    assert_eq!(square((0, 0), (0, 0)).count(), 1);
+}
+
+#[test]
+fn count_beyond_u64() {
+    let interval_box = IntervalBox::new([Interval::<i64>::from_boundaries(0, 1 << 40); 2]);
+    assert_eq!(interval_box.count(), ((1u128 << 40) + 1).pow(2));
+}
+
+#[test]
+fn count_four_dimensions() {
+    let interval_box = IntervalBox::new([Interval::from_boundaries(1, 4000); 4]);
+    assert_eq!(interval_box.count(), 256_000_000_000_000);
+}
+
+// Tests for contains and subsumes
+
+#[test]
+fn contains() {
+    let interval_box = square((1, 4), (-2, 2));
+    assert!(interval_box.contains(&[1, 2]));
+    assert!(interval_box.contains(&[3, 0]));
+    assert!(!interval_box.contains(&[0, 0]));
+    assert!(!interval_box.contains(&[3, 3]));
+}
+
+#[test]
+fn subsumes() {
+    let interval_box = square((1, 4), (-2, 2));
+    assert!(interval_box.subsumes(&square((2, 4), (-2, 0))));
+    assert!(!interval_box.subsumes(&square((2, 5), (-2, 0))));
+}
+
+// Tests for split
+
+#[test]
+fn split_at_within() {
+    let (lower, upper) = square((1, 10), (1, 10)).split_at(0, 4);
+    assert_eq!(lower, Some(square((1, 3), (1, 10))));
+    assert_eq!(upper, Some(square((4, 10), (1, 10))));
+}
+
+#[test]
+fn split_at_boundaries() {
+    let interval_box = square((1, 10), (1, 10));
+    assert_eq!(interval_box.split_at(1, 1), (None, Some(interval_box)));
+    assert_eq!(interval_box.split_at(1, 11), (Some(interval_box), None));
+    assert_eq!(
+        interval_box.split_at(1, 10),
+        (
+            Some(square((1, 10), (1, 9))),
+            Some(square((1, 10), (10, 10)))
+        )
+    );
+}
+
+#[test]
+fn split_less_than() {
+    let (matching, remaining) = square((1, 4000), (1, 4000)).split_less_than(1, 1351);
+    assert_eq!(matching, Some(square((1, 4000), (1, 1350))));
+    assert_eq!(remaining, Some(square((1, 4000), (1351, 4000))));
+}
+
+#[test]
+fn split_greater_than() {
+    let interval_box = square((1, 4000), (1, 4000));
+    let (matching, remaining) = interval_box.split_greater_than(0, 2662);
+    assert_eq!(matching, Some(square((2663, 4000), (1, 4000))));
+    assert_eq!(remaining, Some(square((1, 2662), (1, 4000))));
+    assert_eq!(
+        interval_box.split_greater_than(0, 4000),
+        (None, Some(interval_box))
+    );
+    assert_eq!(
+        interval_box.split_greater_than(0, 0),
+        (Some(interval_box), None)
+    );
+}
+
+#[test]
+fn split_greater_than_maximum_value() {
+    let interval_box = IntervalBox::new([Interval::<i8>::from_boundaries(0, i8::MAX)]);
+    assert_eq!(
+        interval_box.split_greater_than(0, i8::MAX),
+        (None, Some(interval_box))
+    );
+}
+
+// Tests for intersect
+
+#[test]
+fn intersect_overlapping() {
+    let result = square((1, 5), (1, 5)).intersect(&square((3, 8), (0, 2)));
+    assert_some!(result);
+    assert_eq!(result.unwrap(), square((3, 5), (1, 2)));
+}
+
+#[test]
+fn intersect_disjoint_in_one_axis() {
+    assert_none!(square((1, 5), (1, 5)).intersect(&square((3, 8), (6, 9))));
+}
+
+// Tests for subtract
+
+#[test]
+fn subtract_disjoint() {
+    let interval_box = square((1, 5), (1, 5));
+    assert_eq!(
+        interval_box.subtract(&square((6, 8), (1, 5))),
+        vec![interval_box]
+    );
+}
+
+#[test]
+fn subtract_covering() {
+    assert_eq!(
+        square((1, 5), (1, 5)).subtract(&square((0, 5), (1, 9))),
+        vec![]
+    );
+}
+
+#[test]
+fn subtract_hole() {
+    let interval_box = square((1, 5), (1, 5));
+    let hole = square((2, 3), (2, 4));
+    let result = interval_box.subtract(&hole);
+    assert_eq!(
+        result,
+        vec![
+            square((1, 1), (1, 5)),
+            square((4, 5), (1, 5)),
+            square((2, 3), (1, 1)),
+            square((2, 3), (5, 5)),
+        ]
+    );
+    assert_eq!(
+        result.iter().map(|b| b.count()).sum::<u128>(),
+        interval_box.count() - hole.count()
+    );
+}
+
+// Tests for Display
+
+#[test]
+fn display() {
+    assert_eq!(square((1, 5), (-1, 2)).to_string(), "[1, 5] x [-1, 2]");
+}
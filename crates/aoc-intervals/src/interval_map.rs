@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod interval_map_tests;
+
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::interval_value::IntervalValue;
+use std::collections::BTreeMap;
+
+/// Represents a map from disjoint intervals to values.
+///
+/// Every key of an interval is associated to the same value. Inserting an interval that
+/// overlaps with existing ones overwrites the overlapped parts, splitting the existing intervals
+/// if needed.
+///
+/// # Type Parameters
+///
+/// * `K` - The type of the interval boundaries.
+/// * `V` - The type of the values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<K: IntervalValue, V> {
+    /// Stores the intervals and their values, indexed by the minimum value of the interval.
+    entries: BTreeMap<K, (Interval<K>, V)>,
+}
+
+impl<K: IntervalValue, V> IntervalMap<K, V> {
+    /// Default constructor. Initializes an empty `IntervalMap`.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the value associated to a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    ///
+    /// # Returns
+    ///
+    /// The value of the interval containing the key, or `None`.
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// Gets the interval containing a key, and its value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    ///
+    /// # Returns
+    ///
+    /// The interval containing the key and its value, or `None`.
+    pub fn get_entry(&self, key: K) -> Option<(&Interval<K>, &V)> {
+        self.entries
+            .range(..=key)
+            .next_back()
+            .map(|(_, (interval, value))| (interval, value))
+            .filter(|(interval, _)| interval.contains(key))
+    }
+
+    /// Checks if a key is contained in any interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the map has a value for the key, `false` otherwise.
+    pub fn contains_key(&self, key: K) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Gets the parts of the intervals that overlap with the provided interval, and their
+    /// values.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval to look for.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the overlapped fragments of the intervals (clipped to the provided
+    /// interval) and their values, in ascending order.
+    pub fn range(&self, interval: &Interval<K>) -> Vec<(Interval<K>, &V)> {
+        self.overlapping_keys(interval)
+            .into_iter()
+            .map(|key| {
+                let (inner_interval, value) = &self.entries[&key];
+                (inner_interval.intersect(interval).unwrap(), value)
+            })
+            .collect()
+    }
+
+    /// Gets all the keys that have a value.
+    ///
+    /// # Returns
+    ///
+    /// An `IntervalSet` with all the intervals of the map.
+    pub fn domain(&self) -> IntervalSet<K> {
+        IntervalSet::from_vec(
+            self.entries
+                .values()
+                .map(|(interval, _)| *interval)
+                .collect(),
+        )
+    }
+
+    /// Gets the number of intervals.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if there is any interval.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval map is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the intervals and their values, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<K>, &V)> {
+        self.entries
+            .values()
+            .map(|(interval, value)| (interval, value))
+    }
+
+    /// Gets the minimum values of the intervals that overlap with the provided interval, in
+    /// ascending order.
+    fn overlapping_keys(&self, interval: &Interval<K>) -> Vec<K> {
+        let previous = self
+            .entries
+            .range(..interval.get_min())
+            .next_back()
+            .filter(|(_, (inner_interval, _))| inner_interval.get_max() >= interval.get_min())
+            .map(|(key, _)| *key);
+        previous
+            .into_iter()
+            .chain(
+                self.entries
+                    .range(interval.get_min()..=interval.get_max())
+                    .map(|(key, _)| *key),
+            )
+            .collect()
+    }
+}
+
+impl<K: IntervalValue, V: Clone> IntervalMap<K, V> {
+    /// Associates a value to all the keys of an interval.
+    ///
+    /// The parts of existing intervals that overlap with the new one are overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The keys to set.
+    /// * `value` - The value to associate.
+    pub fn insert(&mut self, interval: Interval<K>, value: V) {
+        self.remove(&interval);
+        self.entries.insert(interval.get_min(), (interval, value));
+    }
+
+    /// Removes the values of all the keys of an interval, splitting the existing intervals if
+    /// needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The keys to remove.
+    pub fn remove(&mut self, interval: &Interval<K>) {
+        for key in self.overlapping_keys(interval) {
+            let (inner_interval, value) = self.entries.remove(&key).unwrap();
+            if inner_interval.get_min() < interval.get_min() {
+                // the part on the left of the removed interval is kept
+                let left = Interval::from_boundaries(
+                    inner_interval.get_min(),
+                    interval.get_min() - K::one(),
+                );
+                self.entries.insert(left.get_min(), (left, value.clone()));
+            }
+            if inner_interval.get_max() > interval.get_max() {
+                // the part on the right of the removed interval is kept
+                let right = Interval::from_boundaries(
+                    interval.get_max() + K::one(),
+                    inner_interval.get_max(),
+                );
+                self.entries.insert(right.get_min(), (right, value));
+            }
+        }
+    }
+}
+
+impl<K: IntervalValue, V> Default for IntervalMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
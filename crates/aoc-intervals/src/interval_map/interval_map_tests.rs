@@ -0,0 +1,169 @@
+use super::*;
+use assertables::{assert_none, assert_some};
+use pretty_assertions::assert_eq;
+
+fn entries<V: Clone>(map: &IntervalMap<i32, V>) -> Vec<(Interval<i32>, V)> {
+    map.iter()
+        .map(|(interval, value)| (*interval, value.clone()))
+        .collect()
+}
+
+// Tests for new()
+
+#[test]
+fn new_creates_empty_map() {
+    let map: IntervalMap<i32, char> = IntervalMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.len(), 0);
+    assert_none!(map.get(0));
+}
+
+// Tests for insert()
+
+#[test]
+fn insert_disjoint_intervals() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(10, 20), 'b');
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        entries(&map),
+        vec![
+            (Interval::from_boundaries(1, 5), 'a'),
+            (Interval::from_boundaries(10, 20), 'b'),
+        ]
+    );
+}
+
+#[test]
+fn insert_inside_existing_interval_splits_it() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 10), 'a');
+    map.insert(Interval::from_boundaries(4, 6), 'b');
+    assert_eq!(
+        entries(&map),
+        vec![
+            (Interval::from_boundaries(1, 3), 'a'),
+            (Interval::from_boundaries(4, 6), 'b'),
+            (Interval::from_boundaries(7, 10), 'a'),
+        ]
+    );
+}
+
+#[test]
+fn insert_overwrites_overlapped_parts() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    map.insert(Interval::from_boundaries(8, 9), 'b');
+    map.insert(Interval::from_boundaries(12, 15), 'c');
+    map.insert(Interval::from_boundaries(4, 13), 'd');
+    assert_eq!(
+        entries(&map),
+        vec![
+            (Interval::from_boundaries(1, 3), 'a'),
+            (Interval::from_boundaries(4, 13), 'd'),
+            (Interval::from_boundaries(14, 15), 'c'),
+        ]
+    );
+}
+
+#[test]
+fn insert_same_interval_replaces_value() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    map.insert(Interval::from_boundaries(1, 5), 'b');
+    assert_eq!(entries(&map), vec![(Interval::from_boundaries(1, 5), 'b')]);
+}
+
+// Tests for remove()
+
+#[test]
+fn remove_splits_existing_interval() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 10), 'a');
+    map.remove(&Interval::from_boundaries(3, 4));
+    assert_eq!(
+        entries(&map),
+        vec![
+            (Interval::from_boundaries(1, 2), 'a'),
+            (Interval::from_boundaries(5, 10), 'a'),
+        ]
+    );
+}
+
+#[test]
+fn remove_everything() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 3), 'a');
+    map.insert(Interval::from_boundaries(5, 7), 'b');
+    map.remove(&Interval::from_boundaries(0, 10));
+    assert!(map.is_empty());
+}
+
+// Tests for get() and get_entry()
+
+#[test]
+fn get_returns_value_of_containing_interval() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    map.insert(Interval::from_boundaries(10, 20), 'b');
+    assert_eq!(map.get(1), Some(&'a'));
+    assert_eq!(map.get(5), Some(&'a'));
+    assert_eq!(map.get(15), Some(&'b'));
+    assert_none!(map.get(0));
+    assert_none!(map.get(7));
+    assert_none!(map.get(21));
+}
+
+#[test]
+fn get_entry_returns_interval_and_value() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(10, 20), 'b');
+    let (interval, value) = assert_some!(map.get_entry(12));
+    assert_eq!(*interval, Interval::from_boundaries(10, 20));
+    assert_eq!(*value, 'b');
+    assert!(map.contains_key(20));
+    assert!(!map.contains_key(21));
+}
+
+// Tests for range()
+
+#[test]
+fn range_clips_overlapped_intervals() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    map.insert(Interval::from_boundaries(8, 9), 'b');
+    map.insert(Interval::from_boundaries(12, 15), 'c');
+    assert_eq!(
+        map.range(&Interval::from_boundaries(3, 13)),
+        vec![
+            (Interval::from_boundaries(3, 5), &'a'),
+            (Interval::from_boundaries(8, 9), &'b'),
+            (Interval::from_boundaries(12, 13), &'c'),
+        ]
+    );
+}
+
+#[test]
+fn range_without_overlaps_is_empty() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    assert!(map.range(&Interval::from_boundaries(6, 10)).is_empty());
+}
+
+// Tests for domain()
+
+#[test]
+fn domain_merges_contiguous_intervals() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::from_boundaries(1, 5), 'a');
+    map.insert(Interval::from_boundaries(6, 8), 'b');
+    map.insert(Interval::from_boundaries(20, 30), 'c');
+    assert_eq!(
+        map.domain().get(),
+        vec![
+            Interval::from_boundaries(1, 8),
+            Interval::from_boundaries(20, 30),
+        ]
+    );
+}
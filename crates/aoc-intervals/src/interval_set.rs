@@ -1,29 +1,32 @@
 #[cfg(test)]
 mod interval_set_tests;
 
-use crate::interval::Interval;
+use crate::interval::{Interval, ParseIntervalError};
 use crate::interval_value::{IntervalValue, maximum_interval_value, minimum_interval_value};
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 
 /// Represents a set of non-overlapping intervals.
 ///
 /// This struct provides operations to add and remove intervals or individual values,
 /// automatically merging overlapping intervals to maintain a set of non-overlapping intervals.
 ///
+/// The intervals are kept sorted, so insertions, removals and lookups take `O(log n)` time
+/// (plus the number of merged or split intervals), and iteration is always in ascending order.
+///
 /// # Type Parameters
 ///
 /// * `T` - The type of the interval boundaries, must implement the required traits for integer operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IntervalSet<T: IntervalValue> {
-    /// Stores intervals in a hash set, maintaining no overlaps through merging.
-    intervals: HashSet<Interval<T>>,
+    /// Stores intervals indexed by their minimum value, maintaining no overlaps through merging.
+    intervals: BTreeMap<T, Interval<T>>,
 }
 
 impl<T: IntervalValue> IntervalSet<T> {
     /// Default constructor. Initializes an empty `IntervalSet.
     pub fn new() -> Self {
         Self {
-            intervals: HashSet::new(),
+            intervals: BTreeMap::new(),
         }
     }
 
@@ -34,10 +37,10 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// * `intervals` - A vector of `Interval` to initialize the `IntervalSet`.
     pub fn from_vec(intervals: Vec<Interval<T>>) -> Self {
-        let mut interval_set = Self {
-            intervals: intervals.into_iter().collect(),
-        };
-        interval_set.reduce();
+        let mut interval_set = Self::new();
+        for interval in intervals {
+            interval_set.add_interval(interval);
+        }
         interval_set
     }
 
@@ -47,9 +50,7 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// A vector containing all intervals in the set.
     pub fn get(&self) -> Vec<Interval<T>> {
-        let mut intervals: Vec<_> = self.intervals.iter().copied().collect();
-        intervals.sort();
-        intervals
+        self.intervals.values().copied().collect()
     }
 
     /// Adds an interval, merging it with any overlapping intervals.
@@ -58,8 +59,31 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// * `interval` - The interval to add.
     pub fn add_interval(&mut self, interval: Interval<T>) {
-        self.intervals.insert(interval);
-        self.reduce();
+        let (mut min, mut max) = interval.get_boundaries();
+        // merge with the previous interval if they overlap or are contiguous
+        if let Some(previous) = self.intervals.range(..min).next_back().map(|(_, i)| *i)
+            && previous.get_max() >= min - T::one()
+        {
+            self.intervals.remove(&previous.get_min());
+            min = previous.get_min();
+            max = std::cmp::max(max, previous.get_max());
+        }
+        // merge with all the following intervals that overlap or are contiguous
+        let following: Vec<_> = self
+            .intervals
+            .range(min..)
+            .map(|(_, i)| *i)
+            .take_while(|i| {
+                max.checked_add(&T::one())
+                    .is_none_or(|next_value| i.get_min() <= next_value)
+            })
+            .collect();
+        for next in following {
+            self.intervals.remove(&next.get_min());
+            max = std::cmp::max(max, next.get_max());
+        }
+        self.intervals
+            .insert(min, Interval::from_boundaries(min, max));
     }
 
     /// Adds a new interval defined by a single value.
@@ -79,31 +103,7 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// * `value` - The value to remove.
     pub fn remove_value(&mut self, value: T) {
-        let mut temp_intervals = HashSet::new();
-
-        for item in &self.intervals {
-            if item.contains(value) {
-                if !item.has_one_value() {
-                    if item.get_min() == value {
-                        temp_intervals
-                            .insert(Interval::from_boundaries(value + T::one(), item.get_max()));
-                    } else if item.get_max() == value {
-                        temp_intervals
-                            .insert(Interval::from_boundaries(item.get_min(), value - T::one()));
-                    } else {
-                        temp_intervals
-                            .insert(Interval::from_boundaries(item.get_min(), value - T::one()));
-                        temp_intervals
-                            .insert(Interval::from_boundaries(value + T::one(), item.get_max()));
-                    }
-                }
-            } else {
-                temp_intervals.insert(*item);
-            }
-        }
-
-        self.intervals = temp_intervals;
-        self.reduce();
+        self.remove_interval(&Interval::from_boundaries(value, value));
     }
 
     /// Removes an interval, adjusting or splitting intervals as needed.
@@ -112,45 +112,24 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// * `erase_interval` - The interval to remove.
     pub fn remove_interval(&mut self, erase_interval: &Interval<T>) {
-        let mut temp_intervals = HashSet::new();
-
-        for inner_interval in &self.intervals {
-            if inner_interval == erase_interval {
-                continue;
-            }
-            if inner_interval.subsumes(erase_interval) {
-                // this interval is split in half because of the erased value
-                temp_intervals.insert(Interval::from_boundaries(
+        let (erase_min, erase_max) = erase_interval.get_boundaries();
+        for inner_interval in self.overlapping(erase_interval) {
+            self.intervals.remove(&inner_interval.get_min());
+            if inner_interval.get_min() < erase_min {
+                // the part on the left of the erased interval is kept
+                self.intervals.insert(
                     inner_interval.get_min(),
-                    erase_interval.get_min() - T::one(),
-                ));
-                temp_intervals.insert(Interval::from_boundaries(
-                    erase_interval.get_max() + T::one(),
-                    inner_interval.get_max(),
-                ));
-            } else if erase_interval.get_min() >= inner_interval.get_min()
-                && erase_interval.get_min() <= inner_interval.get_max()
-            {
-                // this interval is not totally subsumed, so it is partially deleted
-                temp_intervals.insert(Interval::from_boundaries(
-                    inner_interval.get_min(),
-                    erase_interval.get_min() - T::one(),
-                ));
-            } else if erase_interval.get_max() >= inner_interval.get_min()
-                && erase_interval.get_max() <= inner_interval.get_max()
-            {
-                // this interval is not totally subsumed so it is partially deleted
-                temp_intervals.insert(Interval::from_boundaries(
-                    erase_interval.get_max() + T::one(),
-                    inner_interval.get_max(),
-                ));
-            } else if !erase_interval.subsumes(inner_interval) {
-                temp_intervals.insert(*inner_interval);
+                    Interval::from_boundaries(inner_interval.get_min(), erase_min - T::one()),
+                );
+            }
+            if inner_interval.get_max() > erase_max {
+                // the part on the right of the erased interval is kept
+                self.intervals.insert(
+                    erase_max + T::one(),
+                    Interval::from_boundaries(erase_max + T::one(), inner_interval.get_max()),
+                );
             }
         }
-
-        self.intervals = temp_intervals;
-        self.reduce();
     }
 
     /// Merges two interval sets.
@@ -163,14 +142,10 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// The result of merging both interval sets.
     pub fn join(&self, other: &Self) -> Self {
-        let mut joined_intervals = self.intervals.clone();
-        for item in &other.intervals {
-            joined_intervals.insert(*item);
+        let mut result = self.clone();
+        for item in other.iter() {
+            result.add_interval(*item);
         }
-        let mut result = Self {
-            intervals: joined_intervals,
-        };
-        result.reduce();
         result
     }
 
@@ -185,7 +160,6 @@ impl<T: IntervalValue> IntervalSet<T> {
     /// `true` if the other interval set is subsumed by this one, `false` otherwise.
     pub fn subsumes_set(&self, other: &Self) -> bool {
         other
-            .intervals
             .iter()
             .all(|other_interval| self.subsumes_interval(other_interval))
     }
@@ -200,9 +174,8 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// `true` if the other interval is subsumed by this interval set, `false` otherwise.
     pub fn subsumes_interval(&self, other: &Interval<T>) -> bool {
-        self.intervals
-            .iter()
-            .any(|interval| interval.subsumes(other))
+        self.get_interval_for(other.get_min())
+            .is_some_and(|interval| interval.subsumes(other))
     }
 
     /// Checks if both interval sets overlap partially or totally.
@@ -216,7 +189,6 @@ impl<T: IntervalValue> IntervalSet<T> {
     /// `true` if they overlap in any way, `false` otherwise.
     pub fn overlaps_set(&self, other: &Self) -> bool {
         other
-            .intervals
             .iter()
             .any(|other_interval| self.overlaps_interval(other_interval))
     }
@@ -231,9 +203,11 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// `true` if they overlap in any way, `false` otherwise.
     pub fn overlaps_interval(&self, other: &Interval<T>) -> bool {
+        // only the last interval starting before the end of `other` can reach it
         self.intervals
-            .iter()
-            .any(|interval| interval.overlaps(other))
+            .range(..=other.get_max())
+            .next_back()
+            .is_some_and(|(_, interval)| interval.get_max() >= other.get_min())
     }
 
     /// Checks if a specific value is contained in the interval set.
@@ -246,18 +220,19 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// `true` if the interval set contains the value, `false` otherwise.
     pub fn contains(&self, value: T) -> bool {
-        self.intervals
-            .iter()
-            .any(|interval| interval.contains(value))
+        self.get_interval_for(value).is_some()
     }
 
     /// Gets the number of contained items.
     ///
     /// # Returns
     ///
-    /// Total number of items.
-    pub fn count(&self) -> u64 {
-        self.intervals.iter().map(Interval::count).sum()
+    /// Total number of items, saturating to `u128::MAX` if it does not fit.
+    pub fn count(&self) -> u128 {
+        self.intervals
+            .values()
+            .map(Interval::count)
+            .fold(0, u128::saturating_add)
     }
 
     /// Extracts a sub `IntervalSet` contained between the selected values.
@@ -272,14 +247,18 @@ impl<T: IntervalValue> IntervalSet<T> {
     /// A sub interval set.
     pub fn extract(&self, min: T, max: T) -> Self {
         let mut result_interval = self.clone();
-        result_interval.remove_interval(&Interval::from_boundaries(
-            minimum_interval_value(),
-            min - T::one(),
-        ));
-        result_interval.remove_interval(&Interval::from_boundaries(
-            max + T::one(),
-            maximum_interval_value(),
-        ));
+        if let Some(before_min) = min.checked_sub(&T::one()) {
+            result_interval.remove_interval(&Interval::from_boundaries(
+                minimum_interval_value(),
+                before_min,
+            ));
+        }
+        if let Some(after_max) = max.checked_add(&T::one()) {
+            result_interval.remove_interval(&Interval::from_boundaries(
+                after_max,
+                maximum_interval_value(),
+            ));
+        }
         result_interval
     }
 
@@ -294,9 +273,10 @@ impl<T: IntervalValue> IntervalSet<T> {
     /// The interval containing this value, or None.
     pub fn get_interval_for(&self, value: T) -> Option<Interval<T>> {
         self.intervals
-            .iter()
-            .find(|interval| interval.contains(value))
-            .copied()
+            .range(..=value)
+            .next_back()
+            .map(|(_, interval)| *interval)
+            .filter(|interval| interval.contains(value))
     }
 
     /// Calculates the overlapping parts of this interval set with the provided interval.
@@ -309,13 +289,134 @@ impl<T: IntervalValue> IntervalSet<T> {
     ///
     /// A new `IntervalSet` with the overlapped fragment.
     pub fn intersect(&self, other: &Interval<T>) -> Self {
-        let mut overlapped_intervals = Vec::new();
-        for internal_interval in &self.intervals {
-            if let Some(result) = internal_interval.intersect(other) {
-                overlapped_intervals.push(result);
+        let mut result = Self::new();
+        for internal_interval in self.overlapping(other) {
+            let overlapped = internal_interval.intersect(other).unwrap();
+            result.intervals.insert(overlapped.get_min(), overlapped);
+        }
+        result
+    }
+
+    /// Calculates the values contained in both interval sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other interval set.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet` with the common values.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let mut self_iter = self.iter().peekable();
+        let mut other_iter = other.iter().peekable();
+        while let (Some(a), Some(b)) = (self_iter.peek(), other_iter.peek()) {
+            if let Some(common) = a.intersect(b) {
+                result.intervals.insert(common.get_min(), common);
+            }
+            // the interval ending first cannot overlap with anything else
+            if a.get_max() < b.get_max() {
+                self_iter.next();
+            } else {
+                other_iter.next();
+            }
+        }
+        result
+    }
+
+    /// Calculates the values contained in this interval set but not in the other one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other interval set.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet` with the remaining values.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for interval in other.iter() {
+            result.remove_interval(interval);
+        }
+        result
+    }
+
+    /// Calculates the values contained in only one of the interval sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other interval set.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet` with the values that are not shared.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.difference(other).join(&other.difference(self))
+    }
+
+    /// Calculates the values of a bounding interval that are not contained in this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The interval with all the values to consider.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet` with the missing values.
+    pub fn complement(&self, bounds: &Interval<T>) -> Self {
+        Self::from_vec(self.gaps(bounds))
+    }
+
+    /// Gets the gaps of this interval set inside a bounding interval, which are the intervals
+    /// of values not contained in the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The interval with all the values to consider.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the gaps, in ascending order.
+    pub fn gaps(&self, bounds: &Interval<T>) -> Vec<Interval<T>> {
+        let mut gaps = Vec::new();
+        let mut next_value = Some(bounds.get_min());
+        for interval in self.overlapping(bounds) {
+            let start = next_value.unwrap();
+            if start < interval.get_min() {
+                gaps.push(Interval::from_boundaries(
+                    start,
+                    interval.get_min() - T::one(),
+                ));
+            }
+            next_value =
+                (interval.get_max() < bounds.get_max()).then(|| interval.get_max() + T::one());
+            if next_value.is_none() {
+                break;
+            }
+        }
+        if let Some(start) = next_value {
+            gaps.push(Interval::from_boundaries(start, bounds.get_max()));
+        }
+        gaps
+    }
+
+    /// Finds the lowest value of a bounding interval that is not contained in this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The interval with all the values to consider.
+    ///
+    /// # Returns
+    ///
+    /// The first missing value, or `None` if the set covers the whole bounding interval.
+    pub fn first_gap_value(&self, bounds: &Interval<T>) -> Option<T> {
+        match self.get_interval_for(bounds.get_min()) {
+            None => Some(bounds.get_min()),
+            // contiguous intervals are always merged, so the next value is not contained
+            Some(interval) => {
+                (interval.get_max() < bounds.get_max()).then(|| interval.get_max() + T::one())
             }
         }
-        Self::from_vec(overlapped_intervals)
     }
 
     /// Checks if there is any interval.
@@ -328,42 +429,78 @@ impl<T: IntervalValue> IntervalSet<T> {
     }
 
     /// Returns an iterator over the intervals.
+    ///
+    /// The intervals are yielded in ascending order.
     pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
-        self.intervals.iter()
+        self.intervals.values()
     }
 
-    /// Merges overlapping intervals within the set to maintain the non-overlapping invariant.
-    fn reduce(&mut self) {
-        if self.intervals.is_empty() {
-            return;
-        }
+    /// Gets a copy of the intervals that overlap with the provided interval, in ascending order.
+    fn overlapping(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        let previous = self
+            .intervals
+            .range(..other.get_min())
+            .next_back()
+            .map(|(_, interval)| *interval)
+            .filter(|interval| interval.get_max() >= other.get_min());
+        previous
+            .into_iter()
+            .chain(
+                self.intervals
+                    .range(other.get_min()..=other.get_max())
+                    .map(|(_, interval)| *interval),
+            )
+            .collect()
+    }
+}
+
+impl<T: IntervalValue> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        let mut intervals: Vec<_> = self.intervals.iter().copied().collect();
-        intervals.sort_by_key(Interval::get_min);
+/// Union operator implementation for IntervalSet.
+///
+/// Equivalent to `join`.
+impl<T: IntervalValue> std::ops::BitOr for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
 
-        let mut merged_intervals = Vec::new();
-        let mut current_interval = intervals[0];
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.join(rhs)
+    }
+}
 
-        for &interval in intervals.iter().skip(1) {
-            if current_interval.get_max() >= interval.get_min() - T::one() {
-                current_interval = Interval::from_boundaries(
-                    current_interval.get_min(),
-                    std::cmp::max(current_interval.get_max(), interval.get_max()),
-                );
-            } else {
-                merged_intervals.push(current_interval);
-                current_interval = interval;
-            }
-        }
-        merged_intervals.push(current_interval);
+/// Intersection operator implementation for IntervalSet.
+///
+/// Equivalent to `intersection`.
+impl<T: IntervalValue> std::ops::BitAnd for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
 
-        self.intervals = merged_intervals.into_iter().collect();
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
     }
 }
 
-impl<T: IntervalValue> Default for IntervalSet<T> {
-    fn default() -> Self {
-        Self::new()
+/// Difference operator implementation for IntervalSet.
+///
+/// Equivalent to `difference`.
+impl<T: IntervalValue> std::ops::Sub for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+/// Symmetric difference operator implementation for IntervalSet.
+///
+/// Equivalent to `symmetric_difference`.
+impl<T: IntervalValue> std::ops::BitXor for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
     }
 }
 
@@ -373,14 +510,55 @@ impl<T: IntervalValue> std::fmt::Display for IntervalSet<T> {
             return write!(f, "");
         }
 
-        let mut intervals: Vec<_> = self.intervals.iter().collect();
-        intervals.sort_by_key(|a| a.get_min());
-
-        let intervals_str: Vec<String> = intervals
-            .iter()
+        let intervals_str: Vec<String> = self
+            .intervals
+            .values()
             .map(|interval| format!("{interval}"))
             .collect();
 
         write!(f, "{}", intervals_str.join(","))
     }
 }
+
+/// Parses an interval set from a list of intervals separated by commas.
+///
+/// Every interval can use any of the formats supported by `Interval`, so the output of
+/// `Display` (like `[1, 3],[5, 7]`) can be parsed back, as well as lists like `1-3,5-7`. An
+/// empty string is an empty set.
+impl<T: IntervalValue + std::str::FromStr> std::str::FromStr for IntervalSet<T> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+        let intervals = if s.starts_with('[') {
+            // the intervals contain commas, so they are split by their closing brackets
+            s.split_inclusive(']')
+                .map(|part| part.trim().trim_start_matches(',').parse())
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            s.split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        Ok(Self::from_vec(intervals))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: IntervalValue + serde::Serialize> serde::Serialize for IntervalSet<T> {
+    /// Serializes the interval set as a sequence of intervals, in ascending order.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: IntervalValue + serde::Deserialize<'de>> serde::Deserialize<'de> for IntervalSet<T> {
+    /// Deserializes the interval set from a sequence of intervals, merging the overlapping ones.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_vec(Vec::deserialize(deserializer)?))
+    }
+}
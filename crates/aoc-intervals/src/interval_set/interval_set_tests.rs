@@ -173,6 +173,24 @@ fn add_subsuming_interval() {
     assert_eq!(intervals[0], interval2);
 }
 
+#[test]
+fn add_interval_merging_several() {
+    let mut set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(1, 2),
+        Interval::from_boundaries(5, 6),
+        Interval::from_boundaries(9, 10),
+        Interval::from_boundaries(20, 21),
+    ]);
+    set.add_interval(Interval::from_boundaries(3, 11));
+    assert_eq!(
+        set.get(),
+        vec![
+            Interval::from_boundaries(1, 11),
+            Interval::from_boundaries(20, 21)
+        ]
+    );
+}
+
 // Tests for add_value()
 
 #[test]
@@ -394,6 +412,24 @@ fn remove_completely_subsuming() {
     assert!(set.is_empty());
 }
 
+#[test]
+fn remove_interval_spanning_several() {
+    let mut set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(1, 5),
+        Interval::from_boundaries(8, 10),
+        Interval::from_boundaries(12, 14),
+        Interval::from_boundaries(20, 25),
+    ]);
+    set.remove_interval(&Interval::from_boundaries(3, 21));
+    assert_eq!(
+        set.get(),
+        vec![
+            Interval::from_boundaries(1, 2),
+            Interval::from_boundaries(22, 25)
+        ]
+    );
+}
+
 // Tests for join()
 
 #[test]
@@ -764,6 +800,22 @@ fn get_interval_for_contained() {
     assert_eq!(set.get_interval_for(30), Some(interval2));
 }
 
+#[test]
+fn get_interval_for_between_intervals() {
+    let set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(1, 10),
+        Interval::from_boundaries(20, 30),
+        Interval::from_boundaries(40, 50),
+    ]);
+    assert_eq!(set.get_interval_for(35), None);
+    assert_eq!(
+        set.get_interval_for(41),
+        Some(Interval::from_boundaries(40, 50))
+    );
+    assert_eq!(set.get_interval_for(0), None);
+    assert_eq!(set.get_interval_for(51), None);
+}
+
 // Tests for intersect()
 
 #[test]
@@ -810,6 +862,105 @@ fn intersect_complete_overlap() {
     assert_eq!(intervals.len(), 1);
 }
 
+// Tests for set algebra
+
+fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+    IntervalSet::from_vec(
+        intervals
+            .iter()
+            .map(|&(min, max)| Interval::from_boundaries(min, max))
+            .collect(),
+    )
+}
+
+#[test]
+fn intersection_sets() {
+    let a = set(&[(1, 5), (10, 20), (30, 40)]);
+    let b = set(&[(3, 12), (15, 16), (18, 35)]);
+    let expected = set(&[(3, 5), (10, 12), (15, 16), (18, 20), (30, 35)]);
+    assert_eq!(a.intersection(&b), expected);
+    assert_eq!(&a & &b, expected);
+    assert_eq!(&b & &a, expected);
+}
+
+#[test]
+fn intersection_with_empty_set() {
+    let a = set(&[(1, 5)]);
+    assert!(a.intersection(&IntervalSet::new()).is_empty());
+}
+
+#[test]
+fn union_operator() {
+    let a = set(&[(1, 5), (20, 25)]);
+    let b = set(&[(6, 8), (30, 31)]);
+    assert_eq!(&a | &b, set(&[(1, 8), (20, 25), (30, 31)]));
+}
+
+#[test]
+fn difference_sets() {
+    let a = set(&[(1, 10), (20, 30)]);
+    let b = set(&[(3, 4), (8, 22), (30, 35)]);
+    let expected = set(&[(1, 2), (5, 7), (23, 29)]);
+    assert_eq!(a.difference(&b), expected);
+    assert_eq!(&a - &b, expected);
+    assert_eq!(&b - &a, set(&[(11, 19), (31, 35)]));
+}
+
+#[test]
+fn symmetric_difference_sets() {
+    let a = set(&[(1, 10)]);
+    let b = set(&[(5, 15)]);
+    let expected = set(&[(1, 4), (11, 15)]);
+    assert_eq!(a.symmetric_difference(&b), expected);
+    assert_eq!(&a ^ &b, expected);
+    assert!((&a ^ &a).is_empty());
+}
+
+#[test]
+fn complement_within_bounds() {
+    let a = set(&[(-5, 2), (5, 6), (9, 20)]);
+    let bounds = Interval::from_boundaries(0, 10);
+    assert_eq!(a.complement(&bounds), set(&[(3, 4), (7, 8)]));
+    assert_eq!(
+        IntervalSet::<i32>::new().complement(&bounds),
+        set(&[(0, 10)])
+    );
+    assert!(set(&[(0, 10)]).complement(&bounds).is_empty());
+}
+
+#[test]
+fn gaps_within_bounds() {
+    let a = set(&[(2, 3), (6, 6)]);
+    assert_eq!(
+        a.gaps(&Interval::from_boundaries(0, 10)),
+        vec![
+            Interval::from_boundaries(0, 1),
+            Interval::from_boundaries(4, 5),
+            Interval::from_boundaries(7, 10),
+        ]
+    );
+    assert_eq!(
+        a.gaps(&Interval::from_boundaries(2, 6)),
+        vec![Interval::from_boundaries(4, 5)]
+    );
+    assert!(a.gaps(&Interval::from_boundaries(2, 3)).is_empty());
+}
+
+#[test]
+fn first_gap_value() {
+    let a = set(&[(-2, 11), (13, 4_000_000)]);
+    assert_eq!(
+        a.first_gap_value(&Interval::from_boundaries(0, 4_000_000)),
+        Some(12)
+    );
+    assert_eq!(
+        a.first_gap_value(&Interval::from_boundaries(-10, 0)),
+        Some(-10)
+    );
+    assert_eq!(a.first_gap_value(&Interval::from_boundaries(13, 20)), None);
+    assert_eq!(a.first_gap_value(&Interval::from_boundaries(0, 11)), None);
+}
+
 // Tests for is_empty()
 
 #[test]
@@ -865,6 +1016,32 @@ fn iter_multiple_intervals() {
     assert_eq!(intervals.len(), 3);
 }
 
+#[test]
+fn iter_is_sorted() {
+    let mut set = IntervalSet::new();
+    for i in (0..100).rev() {
+        set.add_value(i * 3);
+    }
+    let minimums: Vec<_> = set.iter().map(|interval| interval.get_min()).collect();
+    assert_eq!(minimums, (0..100).map(|i| i * 3).collect::<Vec<_>>());
+}
+
+#[test]
+fn many_insertions_and_queries() {
+    let mut set = IntervalSet::new();
+    for i in 0..100_000 {
+        set.add_interval(Interval::from_boundaries(i * 10, i * 10 + 4));
+    }
+    assert_eq!(set.iter().count(), 100_000);
+    assert_eq!(set.count(), 500_000);
+    for i in 0..100_000 {
+        assert!(set.contains(i * 10 + 2));
+        assert!(!set.contains(i * 10 + 7));
+    }
+    set.add_interval(Interval::from_boundaries(0, 1_000_000));
+    assert_eq!(set.get(), vec![Interval::from_boundaries(0, 1_000_000)]);
+}
+
 // Tests with different numeric types
 
 #[test]
@@ -891,6 +1068,37 @@ fn edge_case_maximum_boundaries() {
     assert!(set.contains(i8::MAX));
 }
 
+#[test]
+fn unsigned_values_at_type_boundaries() {
+    let mut set: IntervalSet<u64> = IntervalSet::new();
+    set.add_interval(Interval::from_boundaries(10, u64::MAX));
+    set.add_interval(Interval::from_boundaries(0, 9));
+    assert_eq!(set.get(), vec![Interval::whole()]);
+    assert_eq!(set.count(), 1 << 64);
+    set.remove_value(0);
+    set.remove_value(u64::MAX);
+    assert_eq!(set.get(), vec![Interval::from_boundaries(1, u64::MAX - 1)]);
+    assert_eq!(
+        set.extract(0, 5).get(),
+        vec![Interval::from_boundaries(1, 5)]
+    );
+    assert_eq!(
+        set.extract(20, u64::MAX).get(),
+        vec![Interval::from_boundaries(20, u64::MAX - 1)]
+    );
+}
+
+#[test]
+fn values_of_128_bit_types() {
+    let set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(u128::MAX - 9, u128::MAX),
+        Interval::from_boundaries(0u128, 9),
+    ]);
+    assert_eq!(set.count(), 20);
+    let set = IntervalSet::from_vec(vec![Interval::<i128>::whole()]);
+    assert_eq!(set.count(), u128::MAX);
+}
+
 // Tests for Default trait
 
 #[test]
@@ -970,3 +1178,68 @@ fn fmt_multiple_intervals() {
     let formatted = format!("{}", set);
     assert_eq!(formatted, "[1, 3],[7, 9]");
 }
+
+// Tests for FromStr trait
+
+#[test]
+fn from_str_display_round_trip() {
+    let set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(-10, -5),
+        Interval::from_boundaries(1, 3),
+        Interval::from_boundaries(7, 7),
+    ]);
+    assert_eq!(set.to_string().parse(), Ok(set));
+}
+
+#[test]
+fn from_str_list_of_ranges() {
+    let set: IntervalSet<u32> = "1-3, 2..=5,10..12".parse().unwrap();
+    assert_eq!(
+        set.get(),
+        vec![
+            Interval::from_boundaries(1, 5),
+            Interval::from_boundaries(10, 11),
+        ]
+    );
+}
+
+#[test]
+fn from_str_empty() {
+    assert_eq!("".parse(), Ok(IntervalSet::<i32>::new()));
+    assert_eq!(" ".parse(), Ok(IntervalSet::<i32>::new()));
+}
+
+#[test]
+fn from_str_errors() {
+    assert_eq!(
+        "[1, 3],[4, x]".parse::<IntervalSet<i32>>(),
+        Err(ParseIntervalError::InvalidValue("x".to_string()))
+    );
+    assert_eq!(
+        "1-3,,5-6".parse::<IntervalSet<i32>>(),
+        Err(ParseIntervalError::InvalidFormat("".to_string()))
+    );
+}
+
+// Tests for serde support
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let set = IntervalSet::from_vec(vec![
+        Interval::from_boundaries(1, 3),
+        Interval::from_boundaries(7, 9),
+    ]);
+    let json = serde_json::to_string(&set).unwrap();
+    assert_eq!(json, "[[1,3],[7,9]]");
+    assert_eq!(
+        serde_json::from_str::<IntervalSet<i32>>(&json).unwrap(),
+        set
+    );
+    assert_eq!(
+        serde_json::from_str::<IntervalSet<i32>>("[[5,8],[1,6]]")
+            .unwrap()
+            .get(),
+        vec![Interval::from_boundaries(1, 8)]
+    );
+}
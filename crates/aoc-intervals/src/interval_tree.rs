@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod interval_tree_tests;
+
+use crate::interval::Interval;
+use crate::interval_value::IntervalValue;
+
+/// Represents a collection of labelled intervals that may overlap with each other.
+///
+/// Unlike `IntervalSet`, overlapping intervals are never merged, so every interval keeps its own
+/// value. The intervals are stored sorted by their minimum value, as an implicit balanced binary
+/// tree where every node knows the highest maximum value of its subtree. This allows finding the
+/// intervals containing a value, or overlapping with another interval, in `O(log n + k)` time,
+/// being `k` the number of results.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the interval boundaries.
+/// * `V` - The type of the values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalTree<T: IntervalValue, V> {
+    /// Stores the intervals and their values, sorted by their boundaries.
+    entries: Vec<(Interval<T>, V)>,
+    /// Stores, for every node, the highest maximum value of the intervals in its subtree.
+    subtree_max: Vec<T>,
+}
+
+impl<T: IntervalValue, V> IntervalTree<T, V> {
+    /// Default constructor. Initializes an empty `IntervalTree`.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            subtree_max: Vec::new(),
+        }
+    }
+
+    /// Constructs an `IntervalTree` from a vector of intervals and their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The intervals and their values. They can overlap or be repeated.
+    pub fn from_vec(mut entries: Vec<(Interval<T>, V)>) -> Self {
+        entries.sort_by_key(|(interval, _)| interval.get_boundaries());
+        let mut tree = Self {
+            entries,
+            subtree_max: Vec::new(),
+        };
+        tree.rebuild();
+        tree
+    }
+
+    /// Adds an interval and its value. Existing intervals are kept, even if they overlap.
+    ///
+    /// It takes `O(n)` time, so prefer [`IntervalTree::from_vec`] to add many intervals at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval to add.
+    /// * `value` - The value of the interval.
+    pub fn insert(&mut self, interval: Interval<T>, value: V) {
+        let position = self
+            .entries
+            .partition_point(|(other, _)| other.get_boundaries() <= interval.get_boundaries());
+        self.entries.insert(position, (interval, value));
+        self.rebuild();
+    }
+
+    /// Gets all the intervals that contain a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the intervals containing the value and their values, sorted by their
+    /// boundaries.
+    pub fn containing(&self, value: T) -> Vec<(&Interval<T>, &V)> {
+        self.overlapping(&Interval::from_boundaries(value, value))
+    }
+
+    /// Gets all the intervals that share at least one value with another interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval to look for.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the overlapping intervals and their values, sorted by their boundaries.
+    pub fn overlapping(&self, interval: &Interval<T>) -> Vec<(&Interval<T>, &V)> {
+        let mut results = Vec::new();
+        self.collect_overlapping(0, self.entries.len(), interval, &mut results);
+        results
+    }
+
+    /// Gets the number of intervals.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if there is any interval.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval tree is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the intervals and their values, sorted by their boundaries.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T>, &V)> {
+        self.entries
+            .iter()
+            .map(|(interval, value)| (interval, value))
+    }
+
+    /// Recalculates the highest maximum value of every subtree.
+    fn rebuild(&mut self) {
+        self.subtree_max = self
+            .entries
+            .iter()
+            .map(|(interval, _)| interval.get_max())
+            .collect();
+        self.update_subtree_max(0, self.entries.len());
+    }
+
+    /// Calculates the highest maximum value of the subtree made of the entries in `[start, end)`,
+    /// whose root is the middle entry.
+    fn update_subtree_max(&mut self, start: usize, end: usize) -> Option<T> {
+        if start >= end {
+            return None;
+        }
+        let middle = start + (end - start) / 2;
+        let left = self.update_subtree_max(start, middle);
+        let right = self.update_subtree_max(middle + 1, end);
+        let max = [left, right]
+            .into_iter()
+            .flatten()
+            .fold(self.subtree_max[middle], std::cmp::max);
+        self.subtree_max[middle] = max;
+        Some(max)
+    }
+
+    /// Collects the overlapping intervals of the subtree made of the entries in `[start, end)`,
+    /// in ascending order.
+    fn collect_overlapping<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        interval: &Interval<T>,
+        results: &mut Vec<(&'a Interval<T>, &'a V)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let middle = start + (end - start) / 2;
+        if self.subtree_max[middle] < interval.get_min() {
+            // every interval of the subtree ends before the searched one
+            return;
+        }
+        self.collect_overlapping(start, middle, interval, results);
+        let (current, value) = &self.entries[middle];
+        if current.get_min() > interval.get_max() {
+            // the intervals on the right start even later
+            return;
+        }
+        if current.overlaps(interval) {
+            results.push((current, value));
+        }
+        self.collect_overlapping(middle + 1, end, interval, results);
+    }
+}
+
+impl<T: IntervalValue, V> Default for IntervalTree<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: IntervalValue, V> FromIterator<(Interval<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, V)>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
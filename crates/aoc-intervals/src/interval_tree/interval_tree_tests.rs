@@ -0,0 +1,131 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn ticket_rules() -> IntervalTree<i32, &'static str> {
+    IntervalTree::from_vec(vec![
+        (Interval::from_boundaries(1, 3), "class"),
+        (Interval::from_boundaries(5, 7), "class"),
+        (Interval::from_boundaries(6, 11), "row"),
+        (Interval::from_boundaries(33, 44), "row"),
+        (Interval::from_boundaries(13, 40), "seat"),
+        (Interval::from_boundaries(45, 50), "seat"),
+    ])
+}
+
+fn values(results: Vec<(&Interval<i32>, &&'static str)>) -> Vec<&'static str> {
+    results.into_iter().map(|(_, value)| *value).collect()
+}
+
+// Tests for new()
+
+#[test]
+fn new_creates_empty_tree() {
+    let tree: IntervalTree<i32, ()> = IntervalTree::new();
+    assert!(tree.is_empty());
+    assert_eq!(tree.len(), 0);
+    assert!(tree.containing(0).is_empty());
+}
+
+// Tests for from_vec()
+
+#[test]
+fn from_vec_keeps_overlapping_intervals_sorted() {
+    let tree = ticket_rules();
+    assert_eq!(tree.len(), 6);
+    let intervals: Vec<Interval<i32>> = tree.iter().map(|(interval, _)| *interval).collect();
+    assert_eq!(
+        intervals,
+        vec![
+            Interval::from_boundaries(1, 3),
+            Interval::from_boundaries(5, 7),
+            Interval::from_boundaries(6, 11),
+            Interval::from_boundaries(13, 40),
+            Interval::from_boundaries(33, 44),
+            Interval::from_boundaries(45, 50),
+        ]
+    );
+}
+
+#[test]
+fn from_iter_collects_entries() {
+    let tree: IntervalTree<i32, usize> = (0..5)
+        .map(|i| (Interval::from_boundaries(i, i + 10), i as usize))
+        .collect();
+    assert_eq!(tree.len(), 5);
+    assert_eq!(tree.containing(4).len(), 5);
+}
+
+// Tests for insert()
+
+#[test]
+fn insert_keeps_duplicates() {
+    let mut tree = IntervalTree::new();
+    tree.insert(Interval::from_boundaries(1, 5), 'a');
+    tree.insert(Interval::from_boundaries(1, 5), 'b');
+    tree.insert(Interval::from_boundaries(0, 2), 'c');
+    assert_eq!(tree.len(), 3);
+    let results: Vec<char> = tree.containing(2).into_iter().map(|(_, v)| *v).collect();
+    assert_eq!(results, vec!['c', 'a', 'b']);
+}
+
+// Tests for containing()
+
+#[test]
+fn containing_returns_all_labels() {
+    let tree = ticket_rules();
+    assert_eq!(values(tree.containing(6)), vec!["class", "row"]);
+    assert_eq!(values(tree.containing(35)), vec!["seat", "row"]);
+    assert_eq!(values(tree.containing(45)), vec!["seat"]);
+    assert!(tree.containing(4).is_empty());
+    assert!(tree.containing(12).is_empty());
+    assert!(tree.containing(51).is_empty());
+}
+
+// Tests for overlapping()
+
+#[test]
+fn overlapping_returns_all_labels() {
+    let tree = ticket_rules();
+    assert_eq!(
+        values(tree.overlapping(&Interval::from_boundaries(3, 6))),
+        vec!["class", "class", "row"]
+    );
+    assert_eq!(
+        values(tree.overlapping(&Interval::from_boundaries(41, 100))),
+        vec!["row", "seat"]
+    );
+    assert!(
+        tree.overlapping(&Interval::from_boundaries(-10, 0))
+            .is_empty()
+    );
+}
+
+#[test]
+fn overlapping_matches_linear_search() {
+    let entries: Vec<(Interval<i64>, usize)> = (0..200)
+        .map(|i| {
+            let start = (i * 37) % 101;
+            let length = (i * 13) % 17;
+            (Interval::from_size(start, length + 1), i as usize)
+        })
+        .collect();
+    let tree = IntervalTree::from_vec(entries.clone());
+    for start in -5..120 {
+        for length in [1, 3, 20] {
+            let query = Interval::from_size(start, length);
+            let mut expected: Vec<usize> = entries
+                .iter()
+                .filter(|(interval, _)| interval.overlaps(&query))
+                .map(|(_, value)| *value)
+                .collect();
+            let mut found: Vec<usize> = tree
+                .overlapping(&query)
+                .into_iter()
+                .map(|(_, value)| *value)
+                .collect();
+            expected.sort();
+            found.sort();
+            assert_eq!(found, expected, "Different results for {query}");
+        }
+    }
+}
@@ -2,11 +2,13 @@
 ///
 /// This consolidates all the trait bounds needed for `T` in the `Interval<T>` type,
 /// making it easier to maintain and ensuring consistency across the codebase.
+///
+/// Both signed and unsigned primitive integers are supported, including `i128` and `u128`.
 pub trait IntervalValue:
     num_integer::Integer
-    + num_traits::Signed
     + num_traits::Bounded
     + num_traits::NumCast
+    + num_traits::CheckedAdd
     + num_traits::CheckedSub
     + Copy
     + Ord
@@ -18,9 +20,9 @@ pub trait IntervalValue:
 /// Blanket implementation for any type that satisfies all the required traits.
 impl<T> IntervalValue for T where
     T: num_integer::Integer
-        + num_traits::Signed
         + num_traits::Bounded
         + num_traits::NumCast
+        + num_traits::CheckedAdd
         + num_traits::CheckedSub
         + Copy
         + Ord
@@ -35,7 +37,7 @@ impl<T> IntervalValue for T where
 ///
 /// The minimum valid boundary value for intervals of type `T`
 pub fn minimum_interval_value<T: IntervalValue>() -> T {
-    T::min_value() + T::one()
+    T::min_value()
 }
 
 /// Returns the maximum value that can be used to create a valid interval.
@@ -1,3 +1,9 @@
+pub mod coverage;
+pub mod extended_interval;
 pub mod interval;
+pub mod interval_box;
+pub mod interval_map;
 pub mod interval_set;
+pub mod interval_tree;
 mod interval_value;
+pub mod offset_map;
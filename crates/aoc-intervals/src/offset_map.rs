@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod offset_map_tests;
+
+use crate::interval::Interval;
+use crate::interval_map::IntervalMap;
+use crate::interval_set::IntervalSet;
+use crate::interval_value::IntervalValue;
+use num_traits::cast::cast;
+
+/// Represents a piecewise translation of integer values.
+///
+/// Every interval of source values is moved by its own offset, keeping its size, and the values
+/// outside all the intervals are mapped to themselves. It is useful to chain conversions like
+/// "seed to soil to fertilizer", which can be composed into a single map.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the values.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OffsetMap<T: IntervalValue> {
+    /// Stores the offset (as `i128`) to apply to every interval of source values.
+    offsets: IntervalMap<T, i128>,
+}
+
+impl<T: IntervalValue> OffsetMap<T> {
+    /// Default constructor. Initializes an identity `OffsetMap`.
+    pub fn new() -> Self {
+        Self {
+            offsets: IntervalMap::new(),
+        }
+    }
+
+    /// Moves an interval of source values so that it starts at another value.
+    ///
+    /// It overwrites the overlapped parts of previous sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The values to move.
+    /// * `destination_start` - The value that the minimum of `source` is mapped to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the boundaries of `source` or `destination_start` do not fit in an `i128`.
+    pub fn insert(&mut self, source: Interval<T>, destination_start: T) {
+        let offset = to_wide(destination_start) - to_wide(source.get_min());
+        self.offsets.insert(source, offset);
+    }
+
+    /// Gets the offset applied to a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The source value.
+    ///
+    /// # Returns
+    ///
+    /// The difference between the mapped value and the source value, which is zero for the
+    /// values outside all the intervals.
+    pub fn get_offset(&self, value: T) -> i128 {
+        self.offsets.get(value).copied().unwrap_or(0)
+    }
+
+    /// Gets the underlying map from intervals of source values to their offsets.
+    pub fn get_offsets(&self) -> &IntervalMap<T, i128> {
+        &self.offsets
+    }
+
+    /// Converts a single value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The source value.
+    ///
+    /// # Returns
+    ///
+    /// The mapped value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mapped value does not fit in `T`.
+    pub fn map_value(&self, value: T) -> T {
+        match self.get_offset(value) {
+            0 => value,
+            offset => from_wide(to_wide(value) + offset),
+        }
+    }
+
+    /// Converts all the values of an interval, which may be split into several pieces.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The source values.
+    ///
+    /// # Returns
+    ///
+    /// An `IntervalSet` with all the mapped values.
+    pub fn map_interval(&self, interval: &Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from_vec(
+            self.pieces(interval)
+                .into_iter()
+                .map(|(piece, offset)| shift(&piece, offset))
+                .collect(),
+        )
+    }
+
+    /// Converts all the values of an interval set.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_set` - The source values.
+    ///
+    /// # Returns
+    ///
+    /// An `IntervalSet` with all the mapped values.
+    pub fn map_set(&self, interval_set: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::from_vec(
+            interval_set
+                .iter()
+                .flat_map(|interval| self.pieces(interval))
+                .map(|(piece, offset)| shift(&piece, offset))
+                .collect(),
+        )
+    }
+
+    /// Creates a map equivalent to applying this map and then another one.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - The map to apply after this one.
+    ///
+    /// # Returns
+    ///
+    /// A new `OffsetMap` where every value is mapped to `next.map_value(self.map_value(value))`.
+    /// Only the sections of both maps are visited, so the values outside them never need to fit
+    /// in an `i128`.
+    pub fn compose(&self, next: &Self) -> Self {
+        let mut result = Self::new();
+        // values moved by this map, and then maybe by the next one
+        for (section, &offset) in self.offsets.iter() {
+            for (next_piece, next_offset) in next.pieces(&shift(section, offset)) {
+                if offset + next_offset != 0 {
+                    result
+                        .offsets
+                        .insert(shift(&next_piece, -offset), offset + next_offset);
+                }
+            }
+        }
+        // values only moved by the next map
+        for (next_section, &next_offset) in next.offsets.iter() {
+            for (piece, offset) in self.pieces(next_section) {
+                if offset == 0 && next_offset != 0 {
+                    result.offsets.insert(piece, next_offset);
+                }
+            }
+        }
+        result
+    }
+
+    /// Splits an interval into the pieces that have the same offset, in ascending order.
+    fn pieces(&self, interval: &Interval<T>) -> Vec<(Interval<T>, i128)> {
+        let mut pieces = Vec::new();
+        let mut next_value = Some(interval.get_min());
+        for (section, &offset) in self.offsets.range(interval) {
+            let start = next_value.unwrap();
+            if start < section.get_min() {
+                pieces.push((
+                    Interval::from_boundaries(start, section.get_min() - T::one()),
+                    0,
+                ));
+            }
+            pieces.push((section, offset));
+            next_value =
+                (section.get_max() < interval.get_max()).then(|| section.get_max() + T::one());
+        }
+        if let Some(start) = next_value {
+            pieces.push((Interval::from_boundaries(start, interval.get_max()), 0));
+        }
+        pieces
+    }
+}
+
+/// Moves an interval by an offset.
+fn shift<T: IntervalValue>(interval: &Interval<T>, offset: i128) -> Interval<T> {
+    if offset == 0 {
+        return *interval;
+    }
+    Interval::from_boundaries(
+        from_wide(to_wide(interval.get_min()) + offset),
+        from_wide(to_wide(interval.get_max()) + offset),
+    )
+}
+
+fn to_wide<T: IntervalValue>(value: T) -> i128 {
+    cast(value).expect("Value does not fit in i128")
+}
+
+fn from_wide<T: IntervalValue>(value: i128) -> T {
+    cast(value).expect("Mapped value is out of bounds")
+}
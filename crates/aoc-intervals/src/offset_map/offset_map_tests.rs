@@ -0,0 +1,163 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn build(sections: &[(i64, i64, i64)]) -> OffsetMap<i64> {
+    let mut map = OffsetMap::new();
+    for &(destination, source, length) in sections {
+        map.insert(Interval::from_size(source, length), destination);
+    }
+    map
+}
+
+// Tests for new()
+
+#[test]
+fn new_creates_identity_map() {
+    let map: OffsetMap<i64> = OffsetMap::new();
+    assert!(map.get_offsets().is_empty());
+    assert_eq!(map.map_value(42), 42);
+    assert_eq!(map.get_offset(42), 0);
+}
+
+// Tests for map_value()
+
+#[test]
+fn map_value_inside_section() {
+    assert_eq!(build(&[(50, 98, 2)]).map_value(99), 51);
+    assert_eq!(build(&[(50, 95, 2)]).map_value(96), 51);
+    assert_eq!(build(&[(52, 50, 48)]).map_value(53), 55);
+}
+
+#[test]
+fn map_value_outside_sections() {
+    assert_eq!(build(&[(50, 98, 2)]).map_value(40), 40);
+    assert_eq!(build(&[(52, 50, 48)]).map_value(40), 40);
+}
+
+#[test]
+fn get_offset_can_be_negative() {
+    let map = build(&[(50, 98, 2), (52, 50, 48)]);
+    assert_eq!(map.get_offset(98), -48);
+    assert_eq!(map.get_offset(50), 2);
+    assert_eq!(map.get_offset(100), 0);
+}
+
+// Tests for map_interval()
+
+#[test]
+fn map_interval_inside_section() {
+    let map = build(&[(50, 95, 2)]);
+    assert_eq!(
+        map.map_interval(&Interval::from_boundaries(95, 96)).get(),
+        vec![Interval::from_boundaries(50, 51)]
+    );
+    let map = build(&[(52, 50, 48)]);
+    assert_eq!(
+        map.map_interval(&Interval::from_boundaries(55, 60)).get(),
+        vec![Interval::from_boundaries(57, 62)]
+    );
+}
+
+#[test]
+fn map_interval_crossing_sections() {
+    let map = build(&[(50, 95, 2)]);
+    assert_eq!(
+        map.map_interval(&Interval::from_size(90, 10)).get(),
+        vec![
+            Interval::from_boundaries(50, 51),
+            Interval::from_boundaries(90, 94),
+            Interval::from_boundaries(97, 99),
+        ]
+    );
+    let map = build(&[(52, 50, 48)]);
+    assert_eq!(
+        map.map_interval(&Interval::from_boundaries(45, 100)).get(),
+        vec![
+            Interval::from_boundaries(45, 49),
+            Interval::from_boundaries(52, 100),
+        ]
+    );
+}
+
+// Tests for map_set()
+
+#[test]
+fn map_set_joins_mapped_intervals() {
+    let map = build(&[(50, 98, 2), (52, 50, 48)]);
+    let seeds = IntervalSet::from_vec(vec![
+        Interval::from_size(79, 14),
+        Interval::from_size(55, 13),
+    ]);
+    assert_eq!(
+        map.map_set(&seeds).get(),
+        vec![
+            Interval::from_boundaries(57, 69),
+            Interval::from_boundaries(81, 94),
+        ]
+    );
+}
+
+// Tests for compose()
+
+#[test]
+fn compose_is_equivalent_to_chaining() {
+    let first = build(&[(50, 98, 2), (52, 50, 48)]);
+    let second = build(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+    let composed = first.compose(&second);
+    for value in -10..120 {
+        assert_eq!(
+            composed.map_value(value),
+            second.map_value(first.map_value(value)),
+            "Composition differs for value {value}"
+        );
+    }
+}
+
+#[test]
+fn compose_with_identity() {
+    let map = build(&[(50, 98, 2), (52, 50, 48)]);
+    assert_eq!(map.compose(&OffsetMap::new()), map);
+    assert_eq!(OffsetMap::new().compose(&map), map);
+}
+
+#[test]
+fn compose_with_inverse_is_identity() {
+    let map = build(&[(10, 0, 5)]);
+    let inverse = build(&[(0, 10, 5), (5, 0, 5)]);
+    let composed = map.compose(&inverse);
+    for value in -5..20 {
+        assert_eq!(
+            composed.map_value(value),
+            inverse.map_value(map.map_value(value))
+        );
+    }
+    assert_eq!(composed.map_value(3), 3);
+}
+
+#[test]
+fn compose_unsigned_128_bits() {
+    let mut first = OffsetMap::<u128>::new();
+    first.insert(Interval::from_size(10, 5), 100);
+    let mut second = OffsetMap::<u128>::new();
+    second.insert(Interval::from_size(100, 2), 0);
+    second.insert(Interval::from_size(0, 5), 1_000);
+    let composed = first.compose(&second);
+    for value in 0..120 {
+        assert_eq!(
+            composed.map_value(value),
+            second.map_value(first.map_value(value)),
+            "Composition differs for value {value}"
+        );
+    }
+    assert_eq!(composed.map_value(u128::MAX), u128::MAX);
+}
+
+#[test]
+fn map_interval_whole_unsigned_128_bits() {
+    let mut map = OffsetMap::<u128>::new();
+    map.insert(Interval::from_size(10, 5), 100);
+    // the moved values land on 100..=104, which are also mapped to themselves
+    let mapped = map.map_interval(&Interval::whole());
+    assert_eq!(mapped.count(), u128::MAX - 4);
+    assert!(!mapped.contains(12));
+}
@@ -0,0 +1,161 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, LitStr, Token, Type, parse_macro_input};
+
+/// Arguments of the `scan!` macro: the pattern and the line to scan.
+struct ScanInput {
+    pattern: LitStr,
+    line: Expr,
+}
+
+impl Parse for ScanInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let line = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { pattern, line })
+    }
+}
+
+/// Parses the typed fields of a line that follows a pattern, in the style of `scanf`.
+///
+/// Every `{Type}` in the pattern is a field, which is parsed with `FromStr` as that type. The
+/// pattern is checked at compile time, and it is matched with `aoc_utils::scan::scan_fields`,
+/// so no regular expression is compiled.
+///
+/// # Arguments
+///
+/// * `pattern` - A string literal, like `"Sensor at x={i64}, y={i64}"`.
+/// * `line` - The line to scan.
+///
+/// # Returns
+///
+/// A `ParseResult` with a tuple of all the fields, or an error with the column of the problem.
+///
+/// # Compile errors
+///
+/// The pattern is rejected if it has no fields, if a brace is not closed or opened, if a field
+/// is not a valid type, or if two fields are not separated by any literal text. Braces cannot be
+/// escaped, so the literal text cannot contain them.
+#[proc_macro]
+pub fn scan(input: TokenStream) -> TokenStream {
+    let ScanInput { pattern, line } = parse_macro_input!(input as ScanInput);
+    let (literal_pattern, type_names) = match split_pattern(&pattern.value()) {
+        Ok(split) => split,
+        Err(message) => {
+            return syn::Error::new(pattern.span(), message)
+                .into_compile_error()
+                .into();
+        }
+    };
+    let mut types = Vec::with_capacity(type_names.len());
+    for type_name in &type_names {
+        match syn::parse_str::<Type>(type_name) {
+            Ok(field_type) => types.push(field_type),
+            Err(error) => {
+                let message = format!("invalid type '{type_name}' in the pattern: {error}");
+                return syn::Error::new(pattern.span(), message)
+                    .into_compile_error()
+                    .into();
+            }
+        }
+    }
+    quote! {
+        (|| -> ::aoc_utils::parsing::ParseResult<(#(#types,)*)> {
+            let mut fields = ::aoc_utils::scan::scan_fields(#literal_pattern, #line)?.into_iter();
+            Ok((#({
+                let (column, text) = fields.next().unwrap();
+                ::aoc_utils::parsing::parse_value::<#types>(text, column)?
+            },)*))
+        })()
+    }
+    .into()
+}
+
+/// Splits a `scan!` pattern into the pattern for `scan_fields`, where every field is `{}`, and
+/// the names of the types of the fields.
+fn split_pattern(pattern: &str) -> Result<(String, Vec<String>), String> {
+    let mut literal_pattern = String::with_capacity(pattern.len());
+    let mut type_names = Vec::new();
+    let mut rest = pattern;
+    while let Some(open) = rest.find(['{', '}']) {
+        if rest[open..].starts_with('}') {
+            return Err(format!("unmatched '}}' in pattern '{pattern}'"));
+        }
+        if open == 0 && !type_names.is_empty() {
+            return Err(format!(
+                "fields must be separated by some text in pattern '{pattern}'"
+            ));
+        }
+        literal_pattern.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('}') else {
+            return Err(format!("unclosed '{{' in pattern '{pattern}'"));
+        };
+        let type_name = rest[open + 1..open + close].trim();
+        if type_name.is_empty() || type_name.contains('{') {
+            return Err(format!(
+                "every field needs a type, like '{{i64}}', in pattern '{pattern}'"
+            ));
+        }
+        literal_pattern.push_str("{}");
+        type_names.push(type_name.to_string());
+        rest = &rest[open + close + 1..];
+    }
+    if type_names.is_empty() {
+        return Err(format!("pattern '{pattern}' has no fields"));
+    }
+    literal_pattern.push_str(rest);
+    Ok((literal_pattern, type_names))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(pattern: &str) -> (String, Vec<String>) {
+        split_pattern(pattern).unwrap()
+    }
+
+    #[test]
+    fn split_pattern_valid() {
+        assert_eq!(
+            split("x={i64}, y={ i64 }"),
+            ("x={}, y={}".to_string(), vec!["i64".to_string(); 2])
+        );
+        assert_eq!(
+            split("{String} -> {Vec<u8>}"),
+            (
+                "{} -> {}".to_string(),
+                vec!["String".to_string(), "Vec<u8>".to_string()]
+            )
+        );
+        assert_eq!(
+            split("Monkey {usize}:"),
+            ("Monkey {}:".to_string(), vec!["usize".to_string()])
+        );
+    }
+
+    #[test]
+    fn split_pattern_invalid() {
+        assert!(
+            split_pattern("no fields")
+                .unwrap_err()
+                .contains("has no fields")
+        );
+        assert!(split_pattern("x={i64").unwrap_err().contains("unclosed"));
+        assert!(split_pattern("x=i64}").unwrap_err().contains("unmatched"));
+        assert!(split_pattern("x={}").unwrap_err().contains("needs a type"));
+        assert!(
+            split_pattern("x={{i64}}")
+                .unwrap_err()
+                .contains("needs a type")
+        );
+        assert!(
+            split_pattern("{u8}{u8}")
+                .unwrap_err()
+                .contains("separated by some text")
+        );
+    }
+}
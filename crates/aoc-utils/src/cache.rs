@@ -1 +1,3 @@
+pub mod cycle;
 pub mod loop_cache;
+pub mod memo;
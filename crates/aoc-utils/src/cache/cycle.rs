@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Position of the cycle of a sequence of states generated by repeatedly applying a function.
+///
+/// The sequence is `x0, f(x0), f(f(x0)), ...`, where index 0 is the initial state. The states at
+/// indices `start` and `start + length` are the first pair of repeated states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleInfo {
+    start: usize,
+    length: usize,
+}
+
+impl CycleInfo {
+    /// Creates the position of a cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the first state that is part of the cycle.
+    /// * `length` - The number of states in the cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is zero.
+    pub fn new(start: usize, length: usize) -> Self {
+        assert!(length > 0, "The length of a cycle must be positive");
+        Self { start, length }
+    }
+
+    /// Returns the index of the first state that is part of the cycle.
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of states in the cycle.
+    pub fn get_length(&self) -> usize {
+        self.length
+    }
+
+    /// Calculates the smallest index whose state is the same as the state at another index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Any index of the sequence, which can be much bigger than the cycle.
+    ///
+    /// # Returns
+    ///
+    /// An index lower than `start + length`.
+    pub fn equivalent_index(&self, index: u64) -> usize {
+        let start = self.start as u64;
+        if index < start {
+            index as usize
+        } else {
+            self.start + ((index - start) % self.length as u64) as usize
+        }
+    }
+
+    /// Calculates the state at any index by replaying the sequence from the beginning.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial` - The state at index 0.
+    /// * `next` - The function that generates the following state.
+    /// * `index` - The index of the state.
+    ///
+    /// # Returns
+    ///
+    /// The state at `index`, computed with at most `start + length` steps.
+    pub fn state_at<T, F>(&self, initial: T, mut next: F, index: u64) -> T
+    where
+        F: FnMut(&T) -> T,
+    {
+        (0..self.equivalent_index(index)).fold(initial, |state, _| next(&state))
+    }
+}
+
+/// Cycle of a sequence of states, with all the states seen until it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    info: CycleInfo,
+    history: Vec<T>,
+}
+
+impl<T> Cycle<T> {
+    /// Returns the position of the cycle.
+    pub fn get_info(&self) -> CycleInfo {
+        self.info
+    }
+
+    /// Returns the index of the first state that is part of the cycle.
+    pub fn get_start(&self) -> usize {
+        self.info.start
+    }
+
+    /// Returns the number of states in the cycle.
+    pub fn get_length(&self) -> usize {
+        self.info.length
+    }
+
+    /// Returns the states from index 0 until the end of the first repetition of the cycle.
+    pub fn get_history(&self) -> &[T] {
+        &self.history
+    }
+
+    /// Returns the state at any index, without computing any new state.
+    pub fn state_at(&self, index: u64) -> &T {
+        &self.history[self.info.equivalent_index(index)]
+    }
+}
+
+/// Finds the cycle of a sequence of states, remembering the index of every state in a hash map.
+///
+/// It takes `O(start + length)` time and memory. The sequence must repeat eventually, otherwise
+/// it never returns.
+///
+/// # Arguments
+///
+/// * `initial` - The state at index 0.
+/// * `next` - The function that generates the following state.
+pub fn find_cycle<T, F>(initial: T, next: F) -> Cycle<T>
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    find_cycle_by_key(initial, next, T::clone)
+}
+
+/// Finds the cycle of a sequence of states, comparing only a part of them.
+///
+/// Two states are considered the same if their keys are equal, so the key must contain all the
+/// information that determines the following states.
+///
+/// # Arguments
+///
+/// * `initial` - The state at index 0.
+/// * `next` - The function that generates the following state.
+/// * `key` - The function that extracts the part of a state to compare.
+pub fn find_cycle_by_key<T, K, F, P>(initial: T, mut next: F, mut key: P) -> Cycle<T>
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> T,
+    P: FnMut(&T) -> K,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    loop {
+        let index = history.len();
+        if let Some(&start) = seen.get(&key(&state)) {
+            let info = CycleInfo {
+                start,
+                length: index - start,
+            };
+            return Cycle { info, history };
+        }
+        seen.insert(key(&state), index);
+        let following = next(&state);
+        history.push(state);
+        state = following;
+    }
+}
+
+/// Finds the cycle of a sequence of states with Brent's algorithm.
+///
+/// It only keeps two states in memory, in exchange for computing every state a few times, so it
+/// is useful when the states are big or the cycle is long. Use [`CycleInfo::state_at`] to get
+/// the state at any index.
+///
+/// # Arguments
+///
+/// * `initial` - The state at index 0.
+/// * `next` - The function that generates the following state.
+pub fn brent<T, F>(initial: T, next: F) -> CycleInfo
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    brent_by_key(initial, next, T::clone)
+}
+
+/// Finds the cycle of a sequence of states with Brent's algorithm, comparing only a part of them.
+///
+/// See [`find_cycle_by_key`] for the requirements of the key.
+///
+/// # Arguments
+///
+/// * `initial` - The state at index 0.
+/// * `next` - The function that generates the following state.
+/// * `key` - The function that extracts the part of a state to compare.
+pub fn brent_by_key<T, K, F, P>(initial: T, mut next: F, mut key: P) -> CycleInfo
+where
+    T: Clone,
+    K: PartialEq,
+    F: FnMut(&T) -> T,
+    P: FnMut(&T) -> K,
+{
+    // find the length by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while key(&tortoise) != key(&hare) {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // find the start by moving both at the same speed, `length` states apart
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| next(&state));
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    CycleInfo { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sequence 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, ...
+    fn next(value: &u32) -> u32 {
+        if *value == 6 { 3 } else { value + 1 }
+    }
+
+    #[test]
+    fn find_cycle_metadata() {
+        let cycle = find_cycle(0, next);
+        assert_eq!(cycle.get_start(), 3);
+        assert_eq!(cycle.get_length(), 4);
+        assert_eq!(cycle.get_history(), &[0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*cycle.state_at(2), 2);
+        assert_eq!(*cycle.state_at(7), 3);
+        assert_eq!(*cycle.state_at(1_000_000_000), 3 + (1_000_000_000 - 3) % 4);
+    }
+
+    #[test]
+    fn find_cycle_from_first_state() {
+        let cycle = find_cycle(0u8, |value| (value + 1) % 3);
+        assert_eq!(
+            cycle.get_info(),
+            CycleInfo {
+                start: 0,
+                length: 3
+            }
+        );
+        assert_eq!(*cycle.state_at(10), 1);
+        let cycle = find_cycle(5, |value| *value);
+        assert_eq!(
+            cycle.get_info(),
+            CycleInfo {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn find_cycle_by_key_ignores_counters() {
+        // the step counter never repeats, but it does not affect the position
+        let cycle = find_cycle_by_key(
+            (0, 0),
+            |&(position, step)| (next(&position), step + 1),
+            |state| state.0,
+        );
+        assert_eq!(
+            cycle.get_info(),
+            CycleInfo {
+                start: 3,
+                length: 4
+            }
+        );
+        assert_eq!(*cycle.state_at(9), (5, 5));
+    }
+
+    #[test]
+    fn brent_matches_find_cycle() {
+        let info = brent(0, next);
+        assert_eq!(info, find_cycle(0, next).get_info());
+        assert_eq!(info.equivalent_index(2), 2);
+        assert_eq!(info.equivalent_index(12), 4);
+        assert_eq!(info.state_at(0, next, 1_000_000_001), 5);
+        assert_eq!(
+            brent(7u64, |value| value * value % 1009),
+            find_cycle(7u64, |value| value * value % 1009).get_info()
+        );
+    }
+
+    #[test]
+    fn brent_by_key_ignores_counters() {
+        let info = brent_by_key(
+            (0, 0),
+            |&(position, step)| (next(&position), step + 1),
+            |state| state.0,
+        );
+        assert_eq!(
+            info,
+            CycleInfo {
+                start: 3,
+                length: 4
+            }
+        );
+    }
+}
@@ -1,6 +1,8 @@
 /// Executes an action on an item repeatedly until a maximum number of iterations is reached or a loop is detected.
 ///
 /// Loops are identified and managed to prevent infinite iterations, using internal caching mechanisms.
+/// Every iteration compares the item with all the previous ones, so prefer
+/// [`find_cycle`](crate::cache::cycle::find_cycle) for items that implement `Hash`.
 ///
 /// # Arguments
 ///
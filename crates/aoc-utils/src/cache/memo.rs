@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Number of lookups answered from a cache, and number of values that had to be computed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    /// Returns the fraction of lookups answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Cache of computed values for memoizing recursive functions.
+///
+/// The computation receives the memo itself, so it can call the recursive function again without
+/// threading a separate `HashMap` around.
+///
+/// # Type Parameters
+///
+/// * `K` - The type of the keys, usually a tuple with the arguments of the function.
+/// * `V` - The type of the computed values.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::cache::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// Creates an empty memo.
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Returns the cached value of a key, or computes and caches it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value.
+    /// * `compute` - The computation of the value, which can use the memo for recursive calls.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.lookup(&key) {
+            return value;
+        }
+        let value = compute(self);
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// Returns the cached value of a key, counting the lookup as a hit or a miss.
+    pub fn lookup(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        if value.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        value
+    }
+
+    /// Stores the value of a key, replacing the previous one.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.values.insert(key, value);
+    }
+
+    /// Returns the cached value of a key, without changing the statistics.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Returns the number of cached values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no cached values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the statistics of the lookups.
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// Removes all the cached values and resets the statistics.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Defines a memoized pure function.
+///
+/// The cache is a thread-local [`Memo`] with the given name, whose keys are the tuples of the
+/// arguments, so they must implement `Clone`, `Eq` and `Hash`, and the return type `Clone`.
+/// Recursive calls use the cache too.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::memoize;
+///
+/// memoize! {
+///     static PATHS_MEMO;
+///     fn paths(rows: u64, columns: u64) -> u64 {
+///         if rows == 0 || columns == 0 {
+///             1
+///         } else {
+///             paths(rows - 1, columns) + paths(rows, columns - 1)
+///         }
+///     }
+/// }
+///
+/// assert_eq!(paths(16, 16), 601_080_390);
+/// let stats = PATHS_MEMO.with_borrow(|memo| memo.stats());
+/// assert_eq!(stats.misses, 288);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    (
+        static $memo:ident;
+        $(#[$attribute:meta])*
+        $visibility:vis fn $name:ident($($argument:ident: $type:ty),* $(,)?) -> $output:ty $body:block
+    ) => {
+        thread_local! {
+            static $memo: ::std::cell::RefCell<$crate::cache::memo::Memo<($($type,)*), $output>> =
+                ::std::cell::RefCell::new($crate::cache::memo::Memo::new());
+        }
+
+        $(#[$attribute])*
+        $visibility fn $name($($argument: $type),*) -> $output {
+            fn compute($($argument: $type),*) -> $output $body
+
+            let key = ($($argument.clone(),)*);
+            if let Some(value) = $memo.with_borrow_mut(|memo| memo.lookup(&key)) {
+                return value;
+            }
+            let value = compute($($argument),*);
+            $memo.with_borrow_mut(|memo| memo.insert(key, value.clone()));
+            value
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_compute(n, |memo| match n {
+            0 | 1 => n,
+            _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+        })
+    }
+
+    #[test]
+    fn get_or_compute_recursive() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+        assert_eq!(memo.len(), 51);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 48,
+                misses: 51
+            }
+        );
+        assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+        assert_eq!(memo.stats().hits, 49);
+    }
+
+    #[test]
+    fn stats_and_clear() {
+        let mut memo: Memo<&str, usize> = Memo::new();
+        assert_eq!(memo.stats().hit_rate(), 0.0);
+        assert_eq!(memo.get_or_compute("abc", |_| 3), 3);
+        assert_eq!(memo.get_or_compute("abc", |_| unreachable!()), 3);
+        assert_eq!(memo.stats().hit_rate(), 0.5);
+        assert_eq!(memo.get(&"abc"), Some(&3));
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+
+    crate::memoize! {
+        static COLLATZ_MEMO;
+        fn collatz_steps(n: u64) -> u32 {
+            match n {
+                1 => 0,
+                _ if n.is_multiple_of(2) => 1 + collatz_steps(n / 2),
+                _ => 1 + collatz_steps(3 * n + 1),
+            }
+        }
+    }
+
+    #[test]
+    fn memoize_macro() {
+        assert_eq!(collatz_steps(27), 111);
+        let misses = COLLATZ_MEMO.with_borrow(|memo| memo.stats().misses);
+        assert_eq!(misses, 112);
+        assert_eq!(collatz_steps(54), 112);
+        let stats = COLLATZ_MEMO.with_borrow(|memo| memo.stats());
+        assert_eq!(
+            stats,
+            MemoStats {
+                hits: 1,
+                misses: 113
+            }
+        );
+    }
+}
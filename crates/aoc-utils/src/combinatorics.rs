@@ -0,0 +1,449 @@
+use num_integer::Integer;
+
+/// Iterator over the subsets of `k` items of a slice, in lexicographic order of their indices.
+///
+/// Every partial subset is checked with a predicate before extending it, so whole branches of the
+/// search can be skipped. The items of every subset keep the order of the slice.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the items.
+/// * `F` - The type of the predicate.
+pub struct Subsets<'a, T, F> {
+    items: &'a [T],
+    k: usize,
+    keep: F,
+    /// Stores the indices of the current partial subset.
+    indices: Vec<usize>,
+    /// Stores the current partial subset.
+    selected: Vec<&'a T>,
+    /// Stores the index of the next candidate item to add to the partial subset.
+    next_index: usize,
+    finished: bool,
+}
+
+impl<'a, T, F> Subsets<'a, T, F> {
+    /// Removes the last item of the partial subset and moves to the following candidate.
+    fn backtrack(&mut self) {
+        self.selected.pop();
+        match self.indices.pop() {
+            Some(index) => self.next_index = index + 1,
+            None => self.finished = true,
+        }
+    }
+}
+
+impl<'a, T, F> Iterator for Subsets<'a, T, F>
+where
+    F: FnMut(&[&'a T]) -> bool,
+{
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let remaining = self.k - self.indices.len();
+            if remaining == 0 {
+                let subset = self.selected.clone();
+                self.backtrack();
+                return Some(subset);
+            }
+            if self.next_index + remaining > self.items.len() {
+                // there are not enough items left to complete the subset
+                self.backtrack();
+                continue;
+            }
+            self.indices.push(self.next_index);
+            self.selected.push(&self.items[self.next_index]);
+            if (self.keep)(&self.selected) {
+                self.next_index += 1;
+            } else {
+                self.backtrack();
+            }
+        }
+        None
+    }
+}
+
+/// Returns an iterator over all the subsets of `k` items of a slice.
+///
+/// # Arguments
+///
+/// * `items` - The items to choose from.
+/// * `k` - The number of items of every subset.
+pub fn subsets<T>(items: &[T], k: usize) -> Subsets<'_, T, fn(&[&T]) -> bool> {
+    subsets_with_pruning(items, k, |_| true)
+}
+
+/// Returns an iterator over the subsets of `k` items of a slice, skipping the ones that start
+/// with a rejected partial subset.
+///
+/// # Arguments
+///
+/// * `items` - The items to choose from.
+/// * `k` - The number of items of every subset.
+/// * `keep` - The predicate that receives every partial subset, including the complete ones, and
+///   returns `false` to skip all the subsets that start with it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::combinatorics::subsets_with_pruning;
+///
+/// // the items are sorted, so a partial sum above the target cannot lead to a solution
+/// let items = [1, 2, 3, 4, 5, 6];
+/// let triples: Vec<Vec<&i32>> =
+///     subsets_with_pruning(&items, 3, |partial| partial.iter().copied().sum::<i32>() <= 9)
+///         .filter(|subset| subset.iter().copied().sum::<i32>() == 9)
+///         .collect();
+/// assert_eq!(triples, [[&1, &2, &6], [&1, &3, &5], [&2, &3, &4]]);
+/// ```
+pub fn subsets_with_pruning<'a, T, F>(items: &'a [T], k: usize, keep: F) -> Subsets<'a, T, F>
+where
+    F: FnMut(&[&'a T]) -> bool,
+{
+    Subsets {
+        items,
+        k,
+        keep,
+        indices: Vec::with_capacity(k),
+        selected: Vec::with_capacity(k),
+        next_index: 0,
+        finished: false,
+    }
+}
+
+/// Rearranges a slice into the next permutation in lexicographic order.
+///
+/// Starting from a sorted slice and calling it until it returns `false` visits all the distinct
+/// permutations once, even if there are repeated items.
+///
+/// # Returns
+///
+/// `true` if there was a next permutation, or `false` if the slice was the last one, in which
+/// case it is sorted again.
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(pivot) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        items.reverse();
+        return false;
+    };
+    let successor = (pivot..items.len())
+        .rev()
+        .find(|&i| items[i] > items[pivot - 1])
+        .unwrap();
+    items.swap(pivot - 1, successor);
+    items[pivot..].reverse();
+    true
+}
+
+/// Iterator over the ways to split a total into an ordered list of non-negative parts.
+///
+/// The compositions are generated in lexicographic order, from `[0, ..., 0, total]` to
+/// `[total, 0, ..., 0]`.
+pub struct Compositions {
+    current: Option<Vec<u64>>,
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let composition = self.current.take()?;
+        let last_non_zero = composition.iter().rposition(|&part| part > 0);
+        if let Some(index) = last_non_zero.filter(|&index| index > 0) {
+            // move one unit to the left, and all the rest of the last part to the end
+            let mut next = composition.clone();
+            let rest = next[index] - 1;
+            next[index - 1] += 1;
+            next[index] = 0;
+            *next.last_mut().unwrap() = rest;
+            self.current = Some(next);
+        }
+        Some(composition)
+    }
+}
+
+/// Returns an iterator over the ways to split a total into a number of non-negative parts, like
+/// splitting 100 teaspoons among 4 ingredients.
+///
+/// # Arguments
+///
+/// * `total` - The sum of the parts.
+/// * `parts` - The number of parts. If it is zero, the only composition is the empty one, and
+///   only if `total` is zero.
+pub fn compositions(total: u64, parts: usize) -> Compositions {
+    let current = match parts {
+        0 => (total == 0).then(Vec::new),
+        _ => {
+            let mut first = vec![0; parts];
+            first[parts - 1] = total;
+            Some(first)
+        }
+    };
+    Compositions { current }
+}
+
+/// Iterator over the partitions of a number, with limits on the number of parts and their size.
+///
+/// Every partition has its positive parts in non-increasing order, and the partitions are
+/// generated in descending lexicographic order, from the one with the biggest parts.
+pub struct BoundedPartitions {
+    max_parts: usize,
+    current: Option<Vec<u64>>,
+}
+
+impl BoundedPartitions {
+    /// Appends the parts of the greatest partition of a number to a prefix, if it fits.
+    ///
+    /// # Returns
+    ///
+    /// The completed partition, or `None` if the number cannot be split into parts not greater
+    /// than `max_part` without exceeding `max_parts` in total.
+    fn complete(&self, mut prefix: Vec<u64>, remaining: u64, max_part: u64) -> Option<Vec<u64>> {
+        if remaining == 0 {
+            return Some(prefix);
+        }
+        let available = self.max_parts.saturating_sub(prefix.len()) as u64;
+        if max_part == 0 || remaining.div_ceil(max_part) > available {
+            return None;
+        }
+        // as many parts of the maximum size as possible, and then the rest
+        let (full_parts, rest) = remaining.div_rem(&max_part);
+        prefix.extend(std::iter::repeat_n(max_part, full_parts as usize));
+        if rest > 0 {
+            prefix.push(rest);
+        }
+        Some(prefix)
+    }
+}
+
+impl Iterator for BoundedPartitions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let partition = self.current.take()?;
+        // decrease the rightmost part that can be decreased, and fill the rest with the greatest
+        // parts that are allowed
+        let mut remaining: u64 = 0;
+        for index in (0..partition.len()).rev() {
+            remaining += partition[index];
+            if partition[index] > 1 {
+                let part = partition[index] - 1;
+                let mut prefix = partition[..index].to_vec();
+                prefix.push(part);
+                self.current = self.complete(prefix, remaining - part, part);
+                if self.current.is_some() {
+                    break;
+                }
+            }
+        }
+        Some(partition)
+    }
+}
+
+/// Returns an iterator over the partitions of a number, with limits on the number of parts and
+/// their size.
+///
+/// # Arguments
+///
+/// * `total` - The number to split.
+/// * `max_parts` - The maximum number of parts.
+/// * `max_part` - The maximum value of every part.
+pub fn bounded_partitions(total: u64, max_parts: usize, max_part: u64) -> BoundedPartitions {
+    let mut partitions = BoundedPartitions {
+        max_parts,
+        current: None,
+    };
+    partitions.current = partitions.complete(Vec::new(), total, max_part);
+    partitions
+}
+
+/// Calculates the number of ways to choose `k` items out of `n`.
+///
+/// The intermediate values never exceed the result, so it only fails if the result itself does
+/// not fit.
+///
+/// # Returns
+///
+/// The binomial coefficient, or `None` if it does not fit in an `u128`.
+pub fn binomial(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // result * (n - i) / (i + 1) is C(n, i + 1), which is always an integer
+        let divisor = u128::from(i + 1);
+        let gcd = result.gcd(&divisor);
+        let factor = u128::from(n - i) / (divisor / gcd);
+        result = (result / gcd).checked_mul(factor)?;
+    }
+    Some(result)
+}
+
+/// Calculates the number of ways to split `n = k1 + k2 + ...` distinct items into groups of the
+/// given sizes.
+///
+/// # Arguments
+///
+/// * `counts` - The size of every group.
+///
+/// # Returns
+///
+/// The multinomial coefficient, or `None` if it does not fit in an `u128`.
+pub fn multinomial(counts: &[u64]) -> Option<u128> {
+    let mut total: u64 = 0;
+    let mut result: u128 = 1;
+    for &count in counts {
+        total = total.checked_add(count)?;
+        result = result.checked_mul(binomial(total, count)?)?;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsets_all() {
+        let items = ['a', 'b', 'c', 'd'];
+        let pairs: Vec<String> = subsets(&items, 2)
+            .map(|subset| subset.into_iter().collect())
+            .collect();
+        assert_eq!(pairs, ["ab", "ac", "ad", "bc", "bd", "cd"]);
+        assert_eq!(
+            subsets(&items, 0).collect::<Vec<_>>(),
+            [Vec::<&char>::new()]
+        );
+        assert_eq!(subsets(&items, 4).count(), 1);
+        assert_eq!(subsets(&items, 5).count(), 0);
+        assert_eq!(subsets(&[0u8; 20], 10).count(), 184_756);
+    }
+
+    #[test]
+    fn subsets_pruned_visits_fewer_partial_subsets() {
+        let items: Vec<u32> = (1..=30).collect();
+        let mut visited = 0;
+        let found: Vec<Vec<&u32>> = subsets_with_pruning(&items, 3, |partial| {
+            visited += 1;
+            partial.iter().copied().sum::<u32>() <= 7
+        })
+        .filter(|subset| subset.iter().copied().sum::<u32>() == 7)
+        .collect();
+        assert_eq!(found, [[&1, &2, &4]]);
+        // without pruning, all the C(30, 1) + C(30, 2) + C(30, 3) partial subsets are visited
+        assert!(visited < (30 + 435 + 4060) / 5);
+    }
+
+    #[test]
+    fn next_permutation_order() {
+        let mut items = [1, 2, 2];
+        let mut permutations = vec![items];
+        while next_permutation(&mut items) {
+            permutations.push(items);
+        }
+        assert_eq!(permutations, [[1, 2, 2], [2, 1, 2], [2, 2, 1]]);
+        assert_eq!(items, [1, 2, 2]);
+        let mut items = [0, 1, 2, 3, 4];
+        let mut count = 1;
+        while next_permutation(&mut items) {
+            count += 1;
+        }
+        assert_eq!(count, 120);
+        assert!(!next_permutation::<u8>(&mut []));
+    }
+
+    #[test]
+    fn compositions_values() {
+        assert_eq!(
+            compositions(2, 3).collect::<Vec<_>>(),
+            [
+                [0, 0, 2],
+                [0, 1, 1],
+                [0, 2, 0],
+                [1, 0, 1],
+                [1, 1, 0],
+                [2, 0, 0]
+            ]
+        );
+        assert_eq!(
+            compositions(100, 4).count() as u128,
+            binomial(103, 3).unwrap()
+        );
+        assert!(compositions(100, 4).all(|parts| parts.iter().sum::<u64>() == 100));
+        assert_eq!(compositions(0, 2).collect::<Vec<_>>(), [[0, 0]]);
+        assert_eq!(compositions(5, 1).collect::<Vec<_>>(), [[5]]);
+        assert_eq!(compositions(0, 0).collect::<Vec<_>>(), [Vec::<u64>::new()]);
+        assert_eq!(compositions(1, 0).count(), 0);
+    }
+
+    #[test]
+    fn bounded_partitions_values() {
+        assert_eq!(
+            bounded_partitions(5, 5, 5).collect::<Vec<_>>(),
+            vec![
+                vec![5],
+                vec![4, 1],
+                vec![3, 2],
+                vec![3, 1, 1],
+                vec![2, 2, 1],
+                vec![2, 1, 1, 1],
+                vec![1, 1, 1, 1, 1]
+            ]
+        );
+        assert_eq!(
+            bounded_partitions(5, 2, 3).collect::<Vec<_>>(),
+            vec![vec![3, 2]]
+        );
+        assert_eq!(bounded_partitions(7, 2, 3).count(), 0);
+        assert_eq!(bounded_partitions(3, 1, 0).count(), 0);
+        assert_eq!(
+            bounded_partitions(0, 0, 0).collect::<Vec<_>>(),
+            vec![Vec::<u64>::new()]
+        );
+        assert_eq!(bounded_partitions(30, 30, 30).count(), 5604);
+    }
+
+    #[test]
+    fn bounded_partitions_skips_prefixes_without_room() {
+        // after [3, 3] and [3, 2, 1], every prefix [2, ...] needs 3 parts
+        assert_eq!(
+            bounded_partitions(6, 3, 3).collect::<Vec<_>>(),
+            vec![vec![3, 3], vec![3, 2, 1], vec![2, 2, 2]]
+        );
+        // only the first partitions of a huge number are generated
+        let mut partitions = bounded_partitions(1_000_000, 1_000_000, 1_000_000);
+        assert_eq!(partitions.next(), Some(vec![1_000_000]));
+        assert_eq!(partitions.next(), Some(vec![999_999, 1]));
+    }
+
+    #[test]
+    fn binomial_values() {
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(5, 0), Some(1));
+        assert_eq!(binomial(5, 6), Some(0));
+        assert_eq!(binomial(0, 0), Some(1));
+        assert_eq!(
+            binomial(100, 50),
+            Some(100_891_344_545_564_193_334_812_497_256)
+        );
+        // C(130, 65) is about 9.5e37, so a naive product would overflow
+        assert_eq!(
+            binomial(130, 65),
+            Some(binomial(129, 64).unwrap() + binomial(129, 65).unwrap())
+        );
+        assert_eq!(binomial(200, 100), None);
+        assert_eq!(binomial(u64::MAX, 1), Some(u128::from(u64::MAX)));
+    }
+
+    #[test]
+    fn multinomial_values() {
+        assert_eq!(multinomial(&[2, 1, 1]), Some(12));
+        assert_eq!(multinomial(&[]), Some(1));
+        assert_eq!(multinomial(&[3]), Some(1));
+        // MISSISSIPPI: 1 M, 4 I, 4 S, 2 P
+        assert_eq!(multinomial(&[1, 4, 4, 2]), Some(34_650));
+        assert_eq!(multinomial(&[100, 100]), None);
+    }
+}
@@ -1,4 +1,33 @@
+// lets the code generated by the macros refer to `::aoc_utils` inside this crate too
+extern crate self as aoc_utils;
+
+pub mod cache;
 pub mod cli_utils;
+pub mod combinatorics;
+pub mod linear_system;
+pub mod number_theory;
+pub mod parsing;
 pub mod path_utils;
+pub mod scan;
+pub mod sequence;
 pub mod string_utils;
-pub mod cache;
+
+/// Parses the typed fields of a line that follows a pattern, in the style of `scanf`.
+///
+/// Every `{Type}` in the pattern is a field, which is parsed with `FromStr` as that type, and the
+/// pattern must be a string literal so that it is checked at compile time. See
+/// [`scan::scan_fields`] for the details of the matching.
+///
+/// # Returns
+///
+/// A `ParseResult` with a tuple of all the fields, or an error with the column of the problem.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::scan;
+///
+/// let (x, y, name) = scan!("x={i32}, y={i32} ({String})", "x=-2, y=15 (sensor)").unwrap();
+/// assert_eq!((x, y, name.as_str()), (-2, 15, "sensor"));
+/// ```
+pub use aoc_macros::scan;
@@ -0,0 +1,231 @@
+use num_rational::Ratio;
+use num_traits::Zero;
+
+/// Exact rational number used by the solver.
+pub type Rational = Ratio<i128>;
+
+/// Classification of the solutions of a system of linear equations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearSolution {
+    /// The system has exactly one solution, with the value of every variable.
+    Unique(Vec<Rational>),
+    /// The system has no solution, because some equations contradict each other.
+    Inconsistent,
+    /// The system has infinitely many solutions.
+    Infinite {
+        /// One of the solutions, where all the free variables are zero.
+        particular: Vec<Rational>,
+        /// The indices of the variables that can take any value.
+        free_variables: Vec<usize>,
+    },
+}
+
+impl LinearSolution {
+    /// Checks if the system has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        matches!(self, LinearSolution::Unique(_))
+    }
+
+    /// Gets the unique solution if all its values are integers.
+    ///
+    /// # Returns
+    ///
+    /// The values of the variables, or `None` if the solution is not unique or any value is not
+    /// an integer.
+    pub fn to_integers(&self) -> Option<Vec<i128>> {
+        match self {
+            LinearSolution::Unique(values) => values
+                .iter()
+                .map(|value| value.is_integer().then(|| value.to_integer()))
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Solves a system of linear equations with integer coefficients.
+///
+/// # Arguments
+///
+/// * `coefficients` - The coefficients of every equation, one row per equation and one column
+///   per variable.
+/// * `constants` - The right-hand side of every equation.
+///
+/// # Returns
+///
+/// The exact classification of the solutions. See [`solve_rational`].
+///
+/// # Panics
+///
+/// Panics if the number of rows and constants are different, or if the rows do not have the
+/// same length.
+pub fn solve(coefficients: &[Vec<i128>], constants: &[i128]) -> LinearSolution {
+    let to_rationals = |row: &[i128]| {
+        row.iter()
+            .map(|&value| Rational::from_integer(value))
+            .collect()
+    };
+    let coefficients: Vec<Vec<Rational>> =
+        coefficients.iter().map(|row| to_rationals(row)).collect();
+    solve_rational(&coefficients, &to_rationals(constants))
+}
+
+/// Solves a system of linear equations with rational coefficients, by Gauss-Jordan elimination.
+///
+/// All the operations are exact, unlike with floating point numbers, although the intermediate
+/// values can overflow an `i128` with big or badly conditioned systems.
+///
+/// # Arguments
+///
+/// * `coefficients` - The coefficients of every equation, one row per equation and one column
+///   per variable.
+/// * `constants` - The right-hand side of every equation.
+///
+/// # Panics
+///
+/// Panics if the number of rows and constants are different, or if the rows do not have the
+/// same length.
+pub fn solve_rational(coefficients: &[Vec<Rational>], constants: &[Rational]) -> LinearSolution {
+    assert_eq!(
+        coefficients.len(),
+        constants.len(),
+        "There must be one constant per equation"
+    );
+    let num_variables = coefficients.first().map_or(0, Vec::len);
+    assert!(
+        coefficients.iter().all(|row| row.len() == num_variables),
+        "All the equations must have the same number of coefficients"
+    );
+
+    // augmented matrix, with the constants in the last column
+    let mut rows: Vec<Vec<Rational>> = coefficients
+        .iter()
+        .zip(constants)
+        .map(|(row, &constant)| row.iter().copied().chain([constant]).collect())
+        .collect();
+
+    let mut pivot_columns: Vec<usize> = Vec::new();
+    for column in 0..num_variables {
+        let rank = pivot_columns.len();
+        let Some(pivot_row) = (rank..rows.len()).find(|&row| !rows[row][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot_row);
+        let pivot = rows[rank][column];
+        rows[rank].iter_mut().for_each(|value| *value /= pivot);
+        let pivot_values = rows[rank].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if index != rank && !factor.is_zero() {
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_values).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        pivot_columns.push(column);
+    }
+
+    let rank = pivot_columns.len();
+    if rows[rank..].iter().any(|row| !row[num_variables].is_zero()) {
+        return LinearSolution::Inconsistent;
+    }
+    let mut values = vec![Rational::zero(); num_variables];
+    for (row, &column) in pivot_columns.iter().enumerate() {
+        values[column] = rows[row][num_variables];
+    }
+    if rank == num_variables {
+        LinearSolution::Unique(values)
+    } else {
+        LinearSolution::Infinite {
+            particular: values,
+            free_variables: (0..num_variables)
+                .filter(|column| !pivot_columns.contains(column))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[(i128, i128)]) -> Vec<Rational> {
+        values
+            .iter()
+            .map(|&(numerator, denominator)| Rational::new(numerator, denominator))
+            .collect()
+    }
+
+    #[test]
+    fn solve_unique_integer() {
+        // claw machine: 94a + 22b = 8400, 34a + 67b = 5400
+        let solution = solve(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+        assert!(solution.is_unique());
+        assert_eq!(solution.to_integers(), Some(vec![80, 40]));
+    }
+
+    #[test]
+    fn solve_unique_fraction() {
+        let solution = solve(&[vec![26, 67], vec![66, 21]], &[12748, 12176]);
+        assert!(solution.is_unique());
+        assert_eq!(solution.to_integers(), None);
+        let solution = solve(&[vec![2, 0], vec![0, 3]], &[1, 1]);
+        assert_eq!(
+            solution,
+            LinearSolution::Unique(rationals(&[(1, 2), (1, 3)]))
+        );
+    }
+
+    #[test]
+    fn solve_needs_row_swaps() {
+        let solution = solve(&[vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]], &[5, 4, 3]);
+        assert_eq!(solution.to_integers(), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn solve_inconsistent() {
+        let solution = solve(&[vec![1, 1], vec![2, 2]], &[1, 3]);
+        assert_eq!(solution, LinearSolution::Inconsistent);
+        assert_eq!(solution.to_integers(), None);
+    }
+
+    #[test]
+    fn solve_infinite() {
+        let solution = solve(&[vec![1, 1, 0], vec![2, 2, 0]], &[2, 4]);
+        assert_eq!(
+            solution,
+            LinearSolution::Infinite {
+                particular: rationals(&[(2, 1), (0, 1), (0, 1)]),
+                free_variables: vec![1, 2],
+            }
+        );
+        assert!(!solution.is_unique());
+        assert_eq!(solution.to_integers(), None);
+    }
+
+    #[test]
+    fn solve_overdetermined() {
+        let solution = solve(&[vec![1, 0], vec![0, 1], vec![1, 1]], &[3, 4, 7]);
+        assert_eq!(solution.to_integers(), Some(vec![3, 4]));
+        let solution = solve(&[vec![1, 0], vec![0, 1], vec![1, 1]], &[3, 4, 8]);
+        assert_eq!(solution, LinearSolution::Inconsistent);
+    }
+
+    #[test]
+    fn solve_rational_coefficients() {
+        let coefficients = vec![rationals(&[(1, 2), (1, 3)]), rationals(&[(1, 4), (-1, 1)])];
+        let solution = solve_rational(&coefficients, &rationals(&[(7, 6), (-7, 4)]));
+        assert_eq!(solution.to_integers(), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn solve_empty_system() {
+        assert_eq!(solve(&[], &[]), LinearSolution::Unique(Vec::new()));
+    }
+
+    #[test]
+    #[should_panic(expected = "There must be one constant per equation")]
+    fn solve_wrong_number_of_constants() {
+        let _solution = solve(&[vec![1, 2]], &[1, 2]);
+    }
+}
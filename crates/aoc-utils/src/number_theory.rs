@@ -0,0 +1,265 @@
+//! Modular arithmetic over `i128`.
+//!
+//! All the functions that take a modulus panic if it is not positive, and return residues in
+//! `[0, modulus)`. Products are calculated without overflow, so any modulus up to `i128::MAX` is
+//! valid.
+
+/// Calculates the greatest common divisor of two numbers and the coefficients of Bézout's identity.
+///
+/// # Returns
+///
+/// A tuple `(g, x, y)` where `g` is the non-negative greatest common divisor and
+/// `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Multiplies two numbers modulo another one, without overflowing.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn mod_mul(a: i128, b: i128, modulus: i128) -> i128 {
+    assert!(modulus > 0, "The modulus must be positive");
+    let (a, b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // both factors are lower than 2^127, so their sum never overflows an `u128`
+    let modulus = modulus as u128;
+    let (mut a, mut b) = (a as u128, b as u128);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % modulus;
+        }
+        a = (a + a) % modulus;
+        b >>= 1;
+    }
+    result as i128
+}
+
+/// Raises a number to a power modulo another one, by repeated squaring.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn mod_pow(base: i128, exponent: u128, modulus: i128) -> i128 {
+    assert!(modulus > 0, "The modulus must be positive");
+    let mut base = base.rem_euclid(modulus);
+    let mut exponent = exponent;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Calculates the modular multiplicative inverse of a number.
+///
+/// # Returns
+///
+/// The value `x` such that `a * x ≡ 1 (mod modulus)`, or `None` if `a` and `modulus` are not
+/// coprime.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "The modulus must be positive");
+    let (gcd, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (gcd == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves the linear congruence `a * x ≡ b (mod modulus)`.
+///
+/// # Returns
+///
+/// A tuple `(x, m)` meaning that the solutions are all the values congruent to `x` modulo `m`,
+/// or `None` if there is no solution. `m` divides `modulus`, and is smaller when `a` and
+/// `modulus` are not coprime.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn solve_linear_congruence(a: i128, b: i128, modulus: i128) -> Option<(i128, i128)> {
+    assert!(modulus > 0, "The modulus must be positive");
+    let (gcd, _, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if b.rem_euclid(gcd) != 0 {
+        return None;
+    }
+    let reduced_modulus = modulus / gcd;
+    let inverse = mod_inverse(a.rem_euclid(modulus) / gcd, reduced_modulus)?;
+    Some((
+        mod_mul(b.rem_euclid(modulus) / gcd, inverse, reduced_modulus),
+        reduced_modulus,
+    ))
+}
+
+/// Solves a system of congruences with the Chinese Remainder Theorem.
+///
+/// The moduli do not need to be coprime. In that case the system only has a solution if the
+/// residues agree modulo the greatest common divisor of every pair of moduli.
+///
+/// # Arguments
+///
+/// * `congruences` - The pairs `(residue, modulus)` of every congruence `x ≡ residue (mod modulus)`.
+///
+/// # Returns
+///
+/// A tuple `(x, m)` meaning that the solutions are all the values congruent to `x` modulo `m`,
+/// which is the least common multiple of the moduli, or `None` if there is no solution. An empty
+/// system returns `(0, 1)`.
+///
+/// # Panics
+///
+/// Panics if any modulus is not positive, or if the least common multiple of the moduli does not
+/// fit in an `i128`.
+pub fn chinese_remainder(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(other_residue, other_modulus)| {
+            assert!(other_modulus > 0, "The modulus must be positive");
+            // x = residue + modulus * k, so modulus * k ≡ other_residue - residue (mod other_modulus)
+            let difference = other_residue.rem_euclid(other_modulus) - residue % other_modulus;
+            let (k, k_modulus) = solve_linear_congruence(modulus, difference, other_modulus)?;
+            let combined_modulus = modulus
+                .checked_mul(k_modulus)
+                .expect("The least common multiple of the moduli must fit in an i128");
+            let offset = mod_mul(modulus, k, combined_modulus);
+            Some(((residue + offset) % combined_modulus, combined_modulus))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extended_gcd_bezout() {
+        for (a, b) in [
+            (240, 46),
+            (-240, 46),
+            (240, -46),
+            (0, 7),
+            (7, 0),
+            (0, 0),
+            (17, 17),
+        ] {
+            let (gcd, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, gcd);
+            assert!(gcd >= 0);
+        }
+        assert_eq!(extended_gcd(240, 46).0, 2);
+        assert_eq!(extended_gcd(-12, -18).0, 6);
+        assert_eq!(extended_gcd(0, 0).0, 0);
+    }
+
+    #[test]
+    fn mod_mul_without_overflow() {
+        assert_eq!(mod_mul(-3, 5, 7), 6);
+        let modulus = i128::MAX;
+        assert_eq!(mod_mul(modulus - 1, modulus - 1, modulus), 1);
+        assert_eq!(
+            mod_mul(1 << 100, 1 << 100, (1 << 126) + 1),
+            mod_mul(1 << 74, -1, (1 << 126) + 1)
+        );
+    }
+
+    #[test]
+    fn mod_pow_values() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(7, 0, 13), 1);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        // Fermat's little theorem with the Mersenne prime 2^127 - 1
+        assert_eq!(mod_pow(3, i128::MAX as u128 - 1, i128::MAX), 1);
+    }
+
+    #[test]
+    fn mod_inverse_values() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 1), Some(0));
+        let modulus = 119_315_717_514_047;
+        let inverse = mod_inverse(2020, modulus).unwrap();
+        assert_eq!(mod_mul(2020, inverse, modulus), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "The modulus must be positive")]
+    fn mod_inverse_zero_modulus() {
+        let _inverse = mod_inverse(3, 0);
+    }
+
+    #[test]
+    fn solve_linear_congruence_values() {
+        assert_eq!(solve_linear_congruence(3, 2, 7), Some((3, 7)));
+        assert_eq!(solve_linear_congruence(6, 4, 10), Some((4, 5)));
+        assert_eq!(solve_linear_congruence(6, 3, 10), None);
+        assert_eq!(solve_linear_congruence(10, 0, 5), Some((0, 1)));
+        assert_eq!(solve_linear_congruence(-4, 2, 6), Some((1, 3)));
+    }
+
+    #[test]
+    fn chinese_remainder_coprime() {
+        assert_eq!(
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+        assert_eq!(chinese_remainder(&[(-1, 4)]), Some((3, 4)));
+        // bus schedule with offsets: x + index ≡ 0 (mod bus)
+        let buses = [(0, 7), (1, 13), (4, 59), (6, 31), (7, 19)];
+        let congruences: Vec<(i128, i128)> = buses.iter().map(|&(i, bus)| (-i, bus)).collect();
+        assert_eq!(
+            chinese_remainder(&congruences),
+            Some((1_068_781, 3_162_341))
+        );
+    }
+
+    #[test]
+    fn chinese_remainder_non_coprime() {
+        assert_eq!(chinese_remainder(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[(3, 6), (3, 6)]), Some((3, 6)));
+        assert_eq!(
+            chinese_remainder(&[(0, 12), (0, 18), (0, 8)]),
+            Some((0, 72))
+        );
+    }
+
+    #[test]
+    fn chinese_remainder_large_moduli() {
+        let first = (1i128 << 61) - 1;
+        let second = (1i128 << 31) - 1;
+        let (x, modulus) = chinese_remainder(&[(5, first), (7, second)]).unwrap();
+        assert_eq!(modulus, first * second);
+        assert_eq!((x % first, x % second), (5, 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "The least common multiple of the moduli must fit in an i128")]
+    fn chinese_remainder_overflow() {
+        let _solution = chinese_remainder(&[(0, 1 << 70), (1, (1 << 70) - 1)]);
+    }
+}
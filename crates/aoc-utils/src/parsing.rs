@@ -0,0 +1,313 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Error produced when some input cannot be parsed, with the location of the problem.
+///
+/// Lines and columns are 1-based. Functions that parse a single line report line 1, which can be
+/// replaced with [`ParseError::on_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    /// Creates a new error.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line of the problem (1-based).
+    /// * `column` - The column of the problem (1-based), counted in characters.
+    /// * `message` - The description of the problem.
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Moves the error to another line, keeping its column.
+    pub fn on_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+
+    /// Returns the line of the problem (1-based).
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the problem (1-based).
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the description of the problem.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Result of the parsing functions.
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Parses a value, reporting the location of the text in the line if it fails.
+///
+/// # Arguments
+///
+/// * `text` - The text to parse. Surrounding whitespace is ignored.
+/// * `column` - The column where `text` starts in its line (1-based).
+pub fn parse_value<T>(text: &str, column: usize) -> ParseResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let leading = text.len() - text.trim_start().len();
+    let column = column + text[..leading].chars().count();
+    text.trim().parse().map_err(|error| {
+        ParseError::new(
+            1,
+            column,
+            format!("invalid value '{}': {error}", text.trim()),
+        )
+    })
+}
+
+/// Parses every non-empty line as a value.
+///
+/// # Returns
+///
+/// The values in order, or the first error found.
+pub fn parse_lines<T>(s: &str) -> ParseResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_value(line, 1).map_err(|error| error.on_line(index + 1)))
+        .collect()
+}
+
+/// Splits the input in sections separated by blank lines.
+///
+/// # Returns
+///
+/// The number of the first line of every section (1-based), and its text. Empty sections are
+/// skipped.
+pub fn split_sections(s: &str) -> Vec<(usize, &str)> {
+    let mut sections = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for (index, line) in s.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((number, first)) = start.take() {
+                sections.push((number, s[first..end].trim_end()));
+            }
+        } else {
+            start.get_or_insert((index + 1, offset));
+            end = offset + line.len();
+        }
+        offset += line.len();
+    }
+    if let Some((number, first)) = start {
+        sections.push((number, s[first..end].trim_end()));
+    }
+    sections
+}
+
+/// Parses groups of values, one value per line, separated by blank lines.
+///
+/// # Returns
+///
+/// The values of every group in order, or the first error found.
+pub fn parse_groups<T>(s: &str) -> ParseResult<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    split_sections(s)
+        .into_iter()
+        .map(|(first_line, section)| {
+            parse_lines(section).map_err(|error| {
+                let line = first_line + error.get_line() - 1;
+                error.on_line(line)
+            })
+        })
+        .collect()
+}
+
+/// Extracts all the integers of a line, ignoring any other text.
+///
+/// A `-` is the sign of the number that follows it, unless it comes right after a letter or a
+/// digit, like in `a-1` or `2-4`, where it is considered a separator.
+///
+/// # Returns
+///
+/// The integers in order, or an error if any of them does not fit in `T`.
+pub fn extract_integers<T>(line: &str) -> ParseResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let chars: Vec<char> = line.chars().collect();
+    let mut numbers = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let is_sign = chars[index] == '-'
+            && chars.get(index + 1).is_some_and(char::is_ascii_digit)
+            && (index == 0 || !chars[index - 1].is_alphanumeric());
+        if !is_sign && !chars[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        index += 1;
+        while index < chars.len() && chars[index].is_ascii_digit() {
+            index += 1;
+        }
+        let text: String = chars[start..index].iter().collect();
+        numbers.push(parse_value(&text, start + 1)?);
+    }
+    Ok(numbers)
+}
+
+/// Parses a line with the format `key: value`.
+///
+/// # Returns
+///
+/// The parsed key and value, or an error if the separator is missing or any part is invalid.
+pub fn parse_key_value<K, V>(line: &str) -> ParseResult<(K, V)>
+where
+    K: FromStr,
+    K::Err: Display,
+    V: FromStr,
+    V::Err: Display,
+{
+    let Some((key, value)) = line.split_once(':') else {
+        return Err(ParseError::new(1, 1, format!("missing ':' in '{line}'")));
+    };
+    let value_column = key.chars().count() + 2;
+    Ok((parse_value(key, 1)?, parse_value(value, value_column)?))
+}
+
+/// Parses a line with the format `source -> target1, target2, ...`.
+///
+/// # Returns
+///
+/// The parsed source and the list of targets, which is empty if there is nothing after the
+/// arrow, or an error if the arrow is missing or any part is invalid.
+pub fn parse_arrow_list<K, V>(line: &str) -> ParseResult<(K, Vec<V>)>
+where
+    K: FromStr,
+    K::Err: Display,
+    V: FromStr,
+    V::Err: Display,
+{
+    let Some((source, targets)) = line.split_once("->") else {
+        return Err(ParseError::new(1, 1, format!("missing '->' in '{line}'")));
+    };
+    let mut column = source.chars().count() + 3;
+    let mut values = Vec::new();
+    if !targets.trim().is_empty() {
+        for target in targets.split(',') {
+            values.push(parse_value(target, column)?);
+            column += target.chars().count() + 1;
+        }
+    }
+    Ok((parse_value(source, 1)?, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines_valid() {
+        assert_eq!(parse_lines::<i32>("1\n-2\n\n 3 \n"), Ok(vec![1, -2, 3]));
+    }
+
+    #[test]
+    fn parse_lines_reports_location() {
+        let error = parse_lines::<u32>("1\n2\n  x3\n").unwrap_err();
+        assert_eq!(error.get_line(), 3);
+        assert_eq!(error.get_column(), 3);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: invalid value 'x3': invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn split_sections_with_line_numbers() {
+        let input = "\na\nb\n\n  \nc\r\n\r\nd";
+        assert_eq!(split_sections(input), vec![(2, "a\nb"), (6, "c"), (8, "d")]);
+    }
+
+    #[test]
+    fn parse_groups_valid_and_invalid() {
+        assert_eq!(
+            parse_groups::<u32>("1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        let error = parse_groups::<u32>("1\n2\n\n3\n-4\n").unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (5, 1));
+    }
+
+    #[test]
+    fn extract_integers_signs() {
+        assert_eq!(
+            extract_integers::<i64>("Sensor at x=-2, y=15: beacon at x=3, y=-10"),
+            Ok(vec![-2, 15, 3, -10])
+        );
+        assert_eq!(extract_integers::<i32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(extract_integers::<i32>("no numbers"), Ok(vec![]));
+    }
+
+    #[test]
+    fn extract_integers_overflow() {
+        let error = extract_integers::<u8>("a=1, b=300").unwrap_err();
+        assert_eq!(error.get_column(), 8);
+    }
+
+    #[test]
+    fn parse_key_value_formats() {
+        assert_eq!(
+            parse_key_value::<String, u32>("Time: 7"),
+            Ok(("Time".to_string(), 7))
+        );
+        let error = parse_key_value::<String, u32>("Time: x").unwrap_err();
+        assert_eq!(error.get_column(), 7);
+        assert!(parse_key_value::<String, u32>("Time 7").is_err());
+    }
+
+    #[test]
+    fn parse_arrow_list_formats() {
+        assert_eq!(
+            parse_arrow_list::<String, String>("broadcaster -> a, b, c"),
+            Ok((
+                "broadcaster".to_string(),
+                vec!["a".to_string(), "b".to_string(), "c".to_string()]
+            ))
+        );
+        assert_eq!(parse_arrow_list::<u32, u32>("5 ->"), Ok((5, Vec::new())));
+        let error = parse_arrow_list::<u32, u32>("5 -> 1, x").unwrap_err();
+        assert_eq!(error.get_column(), 9);
+        assert!(parse_arrow_list::<u32, u32>("5 => 1").is_err());
+    }
+}
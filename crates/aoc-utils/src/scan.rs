@@ -0,0 +1,149 @@
+use crate::parsing::{ParseError, ParseResult};
+
+/// Placeholder used in the patterns to mark the fields.
+const PLACEHOLDER: &str = "{}";
+
+/// Extracts the fields of a line that follows a pattern.
+///
+/// The pattern is a literal text where every `{}` marks a field. A field ends where the literal
+/// text that follows it is found for the first time, or at the end of the line if it is the last
+/// part of the pattern. Matching is a simple search, so it is much cheaper than compiling a
+/// regular expression. The [`scan!`](crate::scan!) macro builds on it to parse typed fields.
+///
+/// # Arguments
+///
+/// * `pattern` - The pattern, like `"Sensor at x={}, y={}"`.
+/// * `line` - The line to scan.
+///
+/// # Returns
+///
+/// The column where every field starts (1-based) and its text, or an error if the line does not
+/// follow the pattern.
+///
+/// # Panics
+///
+/// Panics if two fields of the pattern are not separated by any literal text.
+pub fn scan_fields<'a>(pattern: &str, line: &'a str) -> ParseResult<Vec<(usize, &'a str)>> {
+    let literals: Vec<&str> = pattern.split(PLACEHOLDER).collect();
+    assert!(
+        literals.len() < 3
+            || literals[1..literals.len() - 1]
+                .iter()
+                .all(|literal| !literal.is_empty()),
+        "Fields in the pattern must be separated by some text"
+    );
+    let column_of = |position: usize| line[..position].chars().count() + 1;
+    let expect_literal = |position: usize, literal: &str| {
+        if line[position..].starts_with(literal) {
+            Ok(position + literal.len())
+        } else {
+            Err(ParseError::new(
+                1,
+                column_of(position),
+                format!("expected '{literal}' in '{line}'"),
+            ))
+        }
+    };
+
+    let mut position = expect_literal(0, literals[0])?;
+    let mut fields = Vec::with_capacity(literals.len() - 1);
+    for (index, literal) in literals.iter().enumerate().skip(1) {
+        let is_last = index == literals.len() - 1;
+        let end = if is_last && literal.is_empty() {
+            line.len()
+        } else if is_last {
+            // the last literal must be at the end of the line
+            match line[position..].strip_suffix(literal) {
+                Some(field) => position + field.len(),
+                None => line.len().max(position),
+            }
+        } else {
+            match line[position..].find(literal) {
+                Some(offset) => position + offset,
+                None => {
+                    return Err(ParseError::new(
+                        1,
+                        column_of(line.len()),
+                        format!("expected '{literal}' in '{line}'"),
+                    ));
+                }
+            }
+        };
+        fields.push((column_of(position), &line[position..end]));
+        position = expect_literal(end, literal)?;
+    }
+    if position < line.len() {
+        return Err(ParseError::new(
+            1,
+            column_of(position),
+            format!("unexpected text '{}' in '{line}'", &line[position..]),
+        ));
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan;
+
+    #[test]
+    fn scan_fields_valid() {
+        assert_eq!(
+            scan_fields("x={}, y={}", "x=-2, y=15"),
+            Ok(vec![(3, "-2"), (9, "15")])
+        );
+        assert_eq!(
+            scan_fields("{} -> {}", "a -> b, c"),
+            Ok(vec![(1, "a"), (6, "b, c")])
+        );
+        assert_eq!(scan_fields("Monkey {}:", "Monkey 3:"), Ok(vec![(8, "3")]));
+        assert_eq!(scan_fields("no fields", "no fields"), Ok(vec![]));
+    }
+
+    #[test]
+    fn scan_fields_mismatches() {
+        let error = scan_fields("x={}, y={}", "z=1, y=2").unwrap_err();
+        assert_eq!(error.get_column(), 1);
+        let error = scan_fields("x={}, y={}", "x=1; y=2").unwrap_err();
+        assert_eq!(error.get_message(), "expected ', y=' in 'x=1; y=2'");
+        let error = scan_fields("Monkey {}:", "Monkey 3").unwrap_err();
+        assert_eq!(error.get_column(), 9);
+        let error = scan_fields("x={}", "y=").unwrap_err();
+        assert_eq!(error.get_column(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fields in the pattern must be separated by some text")]
+    fn scan_fields_adjacent_placeholders() {
+        let _fields = scan_fields("{}{}", "12");
+    }
+
+    #[test]
+    fn scan_macro_typed_fields() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        let values = scan!(
+            "Sensor at x={i64}, y={i64}: closest beacon is at x={i64}, y={i64}",
+            line
+        );
+        assert_eq!(values, Ok((2, -18, -2, 15)));
+        let values = scan!("{String} -> {u8}", "ab -> 7");
+        assert_eq!(values, Ok(("ab".to_string(), 7)));
+    }
+
+    #[test]
+    fn scan_macro_invalid_value() {
+        let error = scan!(
+            "Valve {String} has flow rate={u32}",
+            "Valve AA has flow rate=x"
+        )
+        .unwrap_err();
+        assert_eq!(error.get_column(), 24);
+    }
+
+    #[test]
+    fn scan_macro_mismatch() {
+        let error = scan!("Monkey {u32}:", "Monkey 3").unwrap_err();
+        assert_eq!(error.get_column(), 9);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::cache::cycle::CycleInfo;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::Signed;
+
+/// Calculates the differences between consecutive values.
+///
+/// The values must be signed, because the differences of a decreasing sequence are negative.
+///
+/// # Returns
+///
+/// A vector with one value less than the input, or empty if there are less than two values.
+pub fn differences<T: Integer + Signed + Copy>(values: &[T]) -> Vec<T> {
+    values
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect()
+}
+
+/// Extrapolates the value that follows a sequence, using finite differences.
+///
+/// The differences are calculated repeatedly until they are all zero, and the next value of every
+/// level is added back. If the sequence comes from a polynomial of degree lower than its length,
+/// the result is the next value of that polynomial.
+///
+/// # Returns
+///
+/// The extrapolated value, or zero if the sequence is empty.
+pub fn extrapolate_next<T: Integer + Signed + Copy>(values: &[T]) -> T {
+    let mut row = values.to_vec();
+    let mut next = T::zero();
+    while row.iter().any(|value| !value.is_zero()) {
+        next = next + row[row.len() - 1];
+        row = differences(&row);
+    }
+    next
+}
+
+/// Extrapolates the value that precedes a sequence, using finite differences.
+///
+/// See [`extrapolate_next`] for the details.
+///
+/// # Returns
+///
+/// The extrapolated value, or zero if the sequence is empty.
+pub fn extrapolate_previous<T: Integer + Signed + Copy>(values: &[T]) -> T {
+    let mut row = values.to_vec();
+    let mut firsts = Vec::new();
+    while row.iter().any(|value| !value.is_zero()) {
+        firsts.push(row[0]);
+        row = differences(&row);
+    }
+    firsts
+        .into_iter()
+        .rev()
+        .fold(T::zero(), |previous, first| first - previous)
+}
+
+/// Finds the degree of the polynomial that generates a sequence.
+///
+/// # Returns
+///
+/// The lowest degree `d` whose `d`-th differences are all equal, or `None` if there are not
+/// enough values to confirm it, which requires at least `d + 2` values.
+pub fn polynomial_degree<T: Integer + Signed + Copy>(values: &[T]) -> Option<usize> {
+    let mut row = values.to_vec();
+    let mut degree = 0;
+    while row.len() >= 2 {
+        if row.iter().all(|&value| value == row[0]) {
+            return Some(degree);
+        }
+        row = differences(&row);
+        degree += 1;
+    }
+    None
+}
+
+/// Finds the shortest period of a sequence that is eventually periodic.
+///
+/// A period is only accepted if it repeats at least twice until the end of the sequence.
+///
+/// # Returns
+///
+/// The position of the cycle, where `start` is the first index of the periodic part, or `None`
+/// if the sequence is not periodic.
+pub fn find_period<T: PartialEq>(values: &[T]) -> Option<CycleInfo> {
+    (1..=values.len() / 2).find_map(|length| {
+        let start = (0..values.len() - length)
+            .rev()
+            .find(|&index| values[index] != values[index + length])
+            .map_or(0, |index| index + 1);
+        (values.len() - start >= 2 * length).then(|| CycleInfo::new(start, length))
+    })
+}
+
+/// Evaluates the polynomial that passes through some points, with Lagrange interpolation.
+///
+/// # Arguments
+///
+/// * `points` - The points `(x, y)` of the polynomial. The polynomial has degree lower than the
+///   number of points.
+/// * `x` - The value where the polynomial is evaluated.
+///
+/// # Returns
+///
+/// The exact value of the polynomial at `x`, which might not be an integer.
+///
+/// # Panics
+///
+/// Panics if two points have the same `x`.
+pub fn lagrange_interpolate(points: &[(i128, i128)], x: i128) -> Ratio<i128> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(xi, yi))| {
+            points.iter().enumerate().filter(|&(j, _)| j != i).fold(
+                Ratio::from_integer(yi),
+                |term, (_, &(xj, _))| {
+                    assert_ne!(xi, xj, "The points must have different x values");
+                    term * Ratio::new(x - xj, xi - xj)
+                },
+            )
+        })
+        .sum()
+}
+
+/// Calculates the coefficients of the polynomial that passes through some points.
+///
+/// # Arguments
+///
+/// * `points` - The points `(x, y)` of the polynomial.
+///
+/// # Returns
+///
+/// The exact coefficients, from the constant term to the highest degree. There is one
+/// coefficient per point, although the highest ones can be zero.
+///
+/// # Panics
+///
+/// Panics if two points have the same `x`.
+pub fn interpolating_polynomial(points: &[(i128, i128)]) -> Vec<Ratio<i128>> {
+    let mut coefficients = vec![Ratio::from_integer(0); points.len()];
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        // basis polynomial, which is 1 at xi and 0 at the other points
+        let mut basis = vec![Ratio::from_integer(1)];
+        for (_, &(xj, _)) in points.iter().enumerate().filter(|&(j, _)| j != i) {
+            assert_ne!(xi, xj, "The points must have different x values");
+            let denominator = Ratio::from_integer(xi - xj);
+            let mut next = vec![Ratio::from_integer(0); basis.len() + 1];
+            for (degree, &coefficient) in basis.iter().enumerate() {
+                next[degree + 1] += coefficient / denominator;
+                next[degree] -= coefficient * Ratio::from_integer(xj) / denominator;
+            }
+            basis = next;
+        }
+        for (coefficient, basis_coefficient) in coefficients.iter_mut().zip(basis) {
+            *coefficient += basis_coefficient * Ratio::from_integer(yi);
+        }
+    }
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolate_polynomials() {
+        assert_eq!(extrapolate_next(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate_next(&[10, 13, 16, 21, 30, 45]), 68);
+        assert_eq!(extrapolate_previous(&[10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(extrapolate_previous(&[0i64, -3, -6]), 3);
+        assert_eq!(extrapolate_next(&[7i8]), 7);
+        assert_eq!(extrapolate_next(&[3i8, 1]), -1);
+        assert_eq!(extrapolate_next::<i32>(&[]), 0);
+        assert_eq!(extrapolate_previous::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn polynomial_degrees() {
+        assert_eq!(polynomial_degree(&[4, 4, 4]), Some(0));
+        assert_eq!(polynomial_degree(&[1, 3, 5, 7]), Some(1));
+        assert_eq!(polynomial_degree(&[0, 1, 4, 9, 16]), Some(2));
+        assert_eq!(polynomial_degree(&[1, 2, 4, 8, 16, 32]), None);
+        assert_eq!(polynomial_degree(&[1]), None);
+    }
+
+    #[test]
+    fn find_period_of_sequences() {
+        assert_eq!(find_period(&[1, 2, 1, 2, 1]), Some(CycleInfo::new(0, 2)));
+        assert_eq!(
+            find_period(&[9, 8, 1, 2, 3, 1, 2, 3, 1]),
+            Some(CycleInfo::new(2, 3))
+        );
+        assert_eq!(find_period(&[5, 5]), Some(CycleInfo::new(0, 1)));
+        assert_eq!(find_period(&[1, 2, 3, 1, 2]), None);
+        assert_eq!(find_period::<u8>(&[]), None);
+    }
+
+    #[test]
+    fn lagrange_quadratic_fit() {
+        // y = 3x^2 - 2x + 5 sampled at three points, like the growth of a garden
+        let polynomial = |x: i128| 3 * x * x - 2 * x + 5;
+        let points = [65, 196, 327].map(|x| (x, polynomial(x)));
+        assert_eq!(
+            lagrange_interpolate(&points, 26_501_365),
+            Ratio::from_integer(polynomial(26_501_365))
+        );
+        assert_eq!(
+            interpolating_polynomial(&points),
+            vec![
+                Ratio::from_integer(5),
+                Ratio::from_integer(-2),
+                Ratio::from_integer(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn lagrange_rational_results() {
+        let points = [(0, 0), (2, 1)];
+        assert_eq!(lagrange_interpolate(&points, 1), Ratio::new(1, 2));
+        assert_eq!(
+            interpolating_polynomial(&points),
+            vec![Ratio::from_integer(0), Ratio::new(1, 2)]
+        );
+        assert_eq!(lagrange_interpolate(&[], 3), Ratio::from_integer(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "The points must have different x values")]
+    fn lagrange_repeated_x() {
+        let _value = lagrange_interpolate(&[(1, 2), (1, 3)], 0);
+    }
+}
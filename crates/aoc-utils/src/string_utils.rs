@@ -12,6 +12,8 @@ pub fn convert_to_list_of_strings(s: &str) -> Vec<String> {
 }
 
 /// Extracts the content of a string as a list of numbers separated by new lines
+///
+/// Lines that are not numbers are skipped. Use [`crate::parsing::parse_lines`] to get an error instead.
 pub fn convert_to_list_of_numbers<T>(s: &str) -> Vec<T>
 where
     T: std::str::FromStr + num_integer::Integer,
@@ -23,6 +25,8 @@ where
 }
 
 /// Extracts the content of a string as groups of numbers, separated by empty lines
+///
+/// Lines that are not numbers are skipped. Use [`crate::parsing::parse_groups`] to get an error instead.
 pub fn convert_to_groups_of_numbers<T>(s: &str) -> Vec<Vec<T>>
 where
     T: std::str::FromStr + num_integer::Integer,
@@ -1,6 +1,34 @@
-use std::collections::HashSet;
+use aoc_utils::combinatorics::subsets_with_pruning;
 
-const TARGET_SUM: u16 = 2020;
+const TARGET_SUM: u64 = 2020;
+
+/// Finds the entries that sum `TARGET_SUM` and multiplies them.
+///
+/// # Arguments
+///
+/// * `input` - The expense report, with one entry per line
+/// * `num_entries` - The number of entries to combine
+///
+/// # Returns
+///
+/// The product of the entries
+fn find_entries_product(input: &str, num_entries: usize) -> u64 {
+    let mut entries: Vec<u64> = input
+        .trim()
+        .lines()
+        .map(|line| line.trim().parse().unwrap())
+        .collect();
+    // the entries are sorted, so no partial sum above the target can reach it
+    entries.sort_unstable();
+    let partial_sum = |entries: &[&u64]| entries.iter().copied().sum::<u64>();
+    subsets_with_pruning(&entries, num_entries, |partial| {
+        partial_sum(partial) <= TARGET_SUM
+    })
+    .find(|subset| partial_sum(subset) == TARGET_SUM)
+    .expect("No entries sum the target")
+    .into_iter()
+    .product()
+}
 
 /// Parameters for solving Part 1 of the puzzle.
 pub struct Part1Parameters {
@@ -17,20 +45,7 @@ pub struct Part1Parameters {
 ///
 /// The solution as a string
 pub fn solve_part1(params: Part1Parameters) -> String {
-    let mut encountered = HashSet::<u16>::new();
-    for value in params
-        .input_data
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse::<u16>().unwrap())
-    {
-        let other_value = TARGET_SUM - value;
-        if encountered.contains(&other_value) {
-            return (value as u32 * other_value as u32).to_string();
-        }
-        encountered.insert(value);
-    }
-    unreachable!();
+    find_entries_product(params.input_data, 2).to_string()
 }
 
 /// Parameters for solving Part 2 of the puzzle.
@@ -48,23 +63,5 @@ pub struct Part2Parameters {
 ///
 /// The solution as a string
 pub fn solve_part2(params: Part2Parameters) -> String {
-    let numbers: HashSet<u16> = params
-        .input_data
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse().unwrap())
-        .collect();
-    for &n1 in &numbers {
-        for &n2 in &numbers {
-            if n1 == n2 {
-                continue;
-            }
-            if let Some(n3) = TARGET_SUM.checked_sub(n1 + n2)
-                && numbers.contains(&n3)
-            {
-                return (n1 as u64 * n2 as u64 * n3 as u64).to_string();
-            }
-        }
-    }
-    unreachable!();
+    find_entries_product(params.input_data, 3).to_string()
 }
@@ -1,4 +1,4 @@
-use aoc_utils::string_utils::convert_to_list_of_numbers;
+use aoc_utils::parsing::parse_lines;
 
 /// Parameters for solving Part 1 of the puzzle.
 pub struct Part1Parameters {
@@ -16,7 +16,7 @@ pub struct Part1Parameters {
 /// The solution as a string
 pub fn solve_part1(params: Part1Parameters) -> String {
     // TODO: Use Iterator::map_windows (https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.map_windows) when it becomes stable to avoid the allocation of a new vector.
-    let input = convert_to_list_of_numbers::<u32>(params.input_data);
+    let input = parse_lines::<u32>(params.input_data).expect("Invalid input");
     let sum: u32 = input
         .windows(2)
         .map(|w| if w[0] < w[1] { 1 } else { 0 })
@@ -39,7 +39,7 @@ pub struct Part2Parameters {
 ///
 /// The solution as a string
 pub fn solve_part2(params: Part2Parameters) -> String {
-    let input = convert_to_list_of_numbers::<u32>(params.input_data);
+    let input = parse_lines::<u32>(params.input_data).expect("Invalid input");
     let windows1 = input.windows(3);
     let windows2 = input.windows(3).skip(1);
     let sum: u32 = windows1
@@ -1,4 +1,4 @@
-use aoc_utils::string_utils::convert_to_groups_of_numbers;
+use aoc_utils::parsing::parse_groups;
 
 /// Parameters for solving Part 1 of the puzzle.
 pub struct Part1Parameters {
@@ -15,7 +15,7 @@ pub struct Part1Parameters {
 ///
 /// The solution as a string
 pub fn solve_part1(params: Part1Parameters) -> String {
-    let groups = convert_to_groups_of_numbers::<u32>(params.input_data);
+    let groups = parse_groups::<u32>(params.input_data).expect("Invalid input");
     let max_calories = groups
         .iter()
         .map(|group| group.iter().sum::<u32>())
@@ -39,7 +39,7 @@ pub struct Part2Parameters {
 ///
 /// The solution as a string
 pub fn solve_part2(params: Part2Parameters) -> String {
-    let groups = convert_to_groups_of_numbers::<u32>(params.input_data);
+    let groups = parse_groups::<u32>(params.input_data).expect("Invalid input");
     let mut group_sums: Vec<u32> = groups
         .iter()
         .map(|group| group.iter().sum::<u32>())
@@ -2,13 +2,7 @@ use aoc_intervals::interval::Interval;
 
 fn parse_input_line(line: &str) -> [Interval<i32>; 2] {
     line.split(',')
-        .map(|part| {
-            let bounds: Vec<i32> = part
-                .split('-')
-                .map(|num| num.parse::<i32>().unwrap())
-                .collect();
-            Interval::from_boundaries(bounds[0], bounds[1])
-        })
+        .map(|part| part.parse::<Interval<i32>>().unwrap())
         .collect::<Vec<_>>()
         .try_into()
         .unwrap()
@@ -6,21 +6,11 @@ use std::collections::HashSet;
 mod rope;
 mod rope_instruction;
 
-fn parse_direction_char(direction: char) -> CardinalDirection2D {
-    match direction {
-        'U' => CardinalDirection2D::Up,
-        'D' => CardinalDirection2D::Down,
-        'L' => CardinalDirection2D::Left,
-        'R' => CardinalDirection2D::Right,
-        _ => unreachable!(),
-    }
-}
-
 fn parse_input_line(line: &str) -> RopeInstruction {
     let mut parts = line.split_whitespace();
     let direction_char = parts.next().unwrap().chars().next().unwrap();
     let steps: u32 = parts.next().unwrap().parse().unwrap();
-    let direction = parse_direction_char(direction_char);
+    let direction = CardinalDirection2D::try_from(direction_char).unwrap();
     RopeInstruction::new(direction, steps)
 }
 
@@ -1,10 +1,10 @@
 mod sensor_info;
 
 use crate::sensor_info::SensorInfo;
-use aoc_geometry::{Point, SquareDiamond2D};
+use aoc_geometry::Point;
 use aoc_intervals::interval::Interval;
 use aoc_intervals::interval_set::IntervalSet;
-use regex::Regex;
+use aoc_utils::scan;
 use std::collections::HashSet;
 
 // -----------------------------------------------------------
@@ -12,14 +12,11 @@ use std::collections::HashSet;
 // -----------------------------------------------------------
 
 fn parse_input_line(line: &str) -> SensorInfo {
-    let re =
-        Regex::new(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")
-            .unwrap();
-    let caps = re.captures(line).expect("Regex failed in parsing the line");
-    let sx: i32 = caps.get(1).unwrap().as_str().parse().unwrap();
-    let sy: i32 = caps.get(2).unwrap().as_str().parse().unwrap();
-    let bx: i32 = caps.get(3).unwrap().as_str().parse().unwrap();
-    let by: i32 = caps.get(4).unwrap().as_str().parse().unwrap();
+    let (sx, sy, bx, by) = scan!(
+        "Sensor at x={i32}, y={i32}: closest beacon is at x={i32}, y={i32}",
+        line
+    )
+    .expect("Invalid sensor line");
     let sensor_position = Point::<i32, 2>::new([sx, sy]);
     let beacon_position = Point::<i32, 2>::new([bx, by]);
     SensorInfo::new(sensor_position, beacon_position)
@@ -97,31 +94,51 @@ fn calculate_tuning_frequency(p: Point<i32, 2>) -> u64 {
     (p[0] as u64 * 4_000_000) + p[1] as u64
 }
 
+/// Finds the rows where the borders just outside the ranges of two sensors cross.
+///
+/// The distress beacon is the only position not covered by any sensor, so it is surrounded by
+/// their ranges, and it is usually at one of these crossings.
+fn candidate_rows(sensor_info_list: &[SensorInfo], max_grid_size: i32) -> Vec<i32> {
+    // every border is made of lines with a constant x + y (sums) or x - y (differences)
+    let mut sums = HashSet::new();
+    let mut differences = HashSet::new();
+    for sensor_info in sensor_info_list {
+        let (x, y) = (
+            i64::from(sensor_info.sensor_position()[0]),
+            i64::from(sensor_info.sensor_position()[1]),
+        );
+        let outside_distance = sensor_info.distance() as i64 + 1;
+        sums.extend([x + y - outside_distance, x + y + outside_distance]);
+        differences.extend([x - y - outside_distance, x - y + outside_distance]);
+    }
+    let mut rows: Vec<i32> = sums
+        .iter()
+        .flat_map(|sum| differences.iter().map(move |difference| sum - difference))
+        .filter(|double_row| double_row % 2 == 0)
+        .filter_map(|double_row| i32::try_from(double_row / 2).ok())
+        .filter(|row| (0..=max_grid_size).contains(row))
+        .collect();
+    rows.sort_unstable();
+    rows.dedup();
+    rows
+}
+
 fn search_for_distress_beacon(
-    sensor_info_list: &Vec<SensorInfo>,
+    sensor_info_list: &[SensorInfo],
     max_grid_size: i32,
 ) -> Point<i32, 2> {
-    for sensor_info in sensor_info_list {
-        let diamond = SquareDiamond2D::from_center_and_perimeter_point(
-            sensor_info.sensor_position(),
-            sensor_info.beacon_position(),
-        );
-        for position in diamond.step_around_outside_border() {
-            if position[0] >= 0
-                && position[0] <= max_grid_size
-                && position[1] >= 0
-                && position[1] <= max_grid_size
-                && sensor_info_list.iter().all(|s| {
-                    let v = aoc_geometry::Vector::<i64, 2>::from_points(
-                        s.sensor_position(),
-                        &position,
-                    )
-                    .unwrap();
-                    v.manhattan_distance() > s.distance()
-                })
-            {
-                return position;
-            }
+    let bounds = Interval::from_boundaries(0, max_grid_size);
+    // the crossings are checked first, and every row only if none of them has the beacon
+    let rows = candidate_rows(sensor_info_list, max_grid_size)
+        .into_iter()
+        .chain(0..=max_grid_size);
+    for row in rows {
+        let mut interval = IntervalSet::new();
+        for sensor_info in sensor_info_list {
+            fill_no_beacon_interval_in_target_row(&mut interval, sensor_info, row);
+        }
+        if let Some(column) = interval.first_gap_value(&bounds) {
+            return Point::new([column, row]);
         }
     }
     unreachable!();
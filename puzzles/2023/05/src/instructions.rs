@@ -1,24 +1,24 @@
-use crate::rangemap::RangeMap;
+use aoc_intervals::offset_map::OffsetMap;
 
 pub struct Instructions {
-    seed2soil_map: RangeMap,
-    soil2fertilizer_map: RangeMap,
-    fertilizer2water_map: RangeMap,
-    water2light_map: RangeMap,
-    light2temperature_map: RangeMap,
-    temperature2humidity_map: RangeMap,
-    humidity2location_map: RangeMap,
+    seed2soil_map: OffsetMap<i64>,
+    soil2fertilizer_map: OffsetMap<i64>,
+    fertilizer2water_map: OffsetMap<i64>,
+    water2light_map: OffsetMap<i64>,
+    light2temperature_map: OffsetMap<i64>,
+    temperature2humidity_map: OffsetMap<i64>,
+    humidity2location_map: OffsetMap<i64>,
 }
 
 impl Instructions {
     pub fn new(
-        seed2soil_map: RangeMap,
-        soil2fertilizer_map: RangeMap,
-        fertilizer2water_map: RangeMap,
-        water2light_map: RangeMap,
-        light2temperature_map: RangeMap,
-        temperature2humidity_map: RangeMap,
-        humidity2location_map: RangeMap,
+        seed2soil_map: OffsetMap<i64>,
+        soil2fertilizer_map: OffsetMap<i64>,
+        fertilizer2water_map: OffsetMap<i64>,
+        water2light_map: OffsetMap<i64>,
+        light2temperature_map: OffsetMap<i64>,
+        temperature2humidity_map: OffsetMap<i64>,
+        humidity2location_map: OffsetMap<i64>,
     ) -> Self {
         Self {
             seed2soil_map,
@@ -31,31 +31,14 @@ impl Instructions {
         }
     }
 
-    pub fn seed2soil(&self) -> &RangeMap {
-        &self.seed2soil_map
-    }
-
-    pub fn soil2fertilizer(&self) -> &RangeMap {
-        &self.soil2fertilizer_map
-    }
-
-    pub fn fertilizer2water(&self) -> &RangeMap {
-        &self.fertilizer2water_map
-    }
-
-    pub fn water2light(&self) -> &RangeMap {
-        &self.water2light_map
-    }
-
-    pub fn light2temperature(&self) -> &RangeMap {
-        &self.light2temperature_map
-    }
-
-    pub fn temperature2humidity(&self) -> &RangeMap {
-        &self.temperature2humidity_map
-    }
-
-    pub fn humidity2location(&self) -> &RangeMap {
-        &self.humidity2location_map
+    /// Composes all the maps into a single one that converts seeds into locations.
+    pub fn seed2location(&self) -> OffsetMap<i64> {
+        self.seed2soil_map
+            .compose(&self.soil2fertilizer_map)
+            .compose(&self.fertilizer2water_map)
+            .compose(&self.water2light_map)
+            .compose(&self.light2temperature_map)
+            .compose(&self.temperature2humidity_map)
+            .compose(&self.humidity2location_map)
     }
 }
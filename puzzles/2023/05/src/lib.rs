@@ -1,14 +1,12 @@
 use crate::instructions::Instructions;
-use crate::rangemap::RangeMap;
 use aoc_intervals::interval::Interval;
 use aoc_intervals::interval_set::IntervalSet;
+use aoc_intervals::offset_map::OffsetMap;
 
 mod instructions;
-mod rangemap;
-mod rangemap_section;
 
-fn parse_map(block: &str) -> RangeMap {
-    let mut map = RangeMap::new();
+fn parse_map(block: &str) -> OffsetMap<i64> {
+    let mut map = OffsetMap::new();
     for line in block.lines().skip(1) {
         // skip the title
         let nums: Vec<i64> = line
@@ -16,7 +14,7 @@ fn parse_map(block: &str) -> RangeMap {
             .map(|s| s.parse::<i64>().unwrap())
             .collect();
         assert_eq!(nums.len(), 3);
-        map.add_section(nums[0], nums[1], nums[2]);
+        map.insert(Interval::from_size(nums[1], nums[2]), nums[0]);
     }
     map
 }
@@ -36,7 +34,7 @@ fn parse_input_for_part1(input: &str) -> (Vec<i64>, Instructions) {
     let mut sections = input.trim().split("\n\n");
     let seeds_line = sections.next().unwrap().trim();
     let seeds = parse_individual_seeds(seeds_line.trim_start_matches("seeds: "));
-    let maps: Vec<RangeMap> = sections.map(parse_map).collect();
+    let maps: Vec<OffsetMap<i64>> = sections.map(parse_map).collect();
     assert_eq!(maps.len(), 7, "Expected 7 mapping blocks");
     let instructions = Instructions::new(
         maps[0].clone(),
@@ -50,22 +48,6 @@ fn parse_input_for_part1(input: &str) -> (Vec<i64>, Instructions) {
     (seeds, instructions)
 }
 
-fn get_seed_location(instructions: &Instructions, seed: i64) -> i64 {
-    instructions.humidity2location().convert_key(
-        instructions.temperature2humidity().convert_key(
-            instructions.light2temperature().convert_key(
-                instructions.water2light().convert_key(
-                    instructions.fertilizer2water().convert_key(
-                        instructions
-                            .soil2fertilizer()
-                            .convert_key(instructions.seed2soil().convert_key(seed)),
-                    ),
-                ),
-            ),
-        ),
-    )
-}
-
 /// Solves Part 1 of the puzzle
 ///
 /// # Arguments
@@ -77,9 +59,10 @@ fn get_seed_location(instructions: &Instructions, seed: i64) -> i64 {
 /// The solution as a string
 pub fn solve_part1(params: Part1Parameters) -> String {
     let (seeds, instructions) = parse_input_for_part1(params.input_data);
+    let seed2location = instructions.seed2location();
     seeds
         .iter()
-        .map(|x| get_seed_location(&instructions, *x))
+        .map(|seed| seed2location.map_value(*seed))
         .min()
         .unwrap()
         .to_string()
@@ -107,7 +90,7 @@ fn parse_input_for_part2(input: &str) -> (IntervalSet<i64>, Instructions) {
     let mut sections = input.trim().split("\n\n");
     let seeds_line = sections.next().unwrap().trim();
     let intervals = parse_ranged_seeds(seeds_line.trim_start_matches("seeds: "));
-    let maps: Vec<RangeMap> = sections.map(parse_map).collect();
+    let maps: Vec<OffsetMap<i64>> = sections.map(parse_map).collect();
     assert_eq!(maps.len(), 7, "Expected 7 mapping blocks");
     let instructions = Instructions::new(
         maps[0].clone(),
@@ -121,39 +104,6 @@ fn parse_input_for_part2(input: &str) -> (IntervalSet<i64>, Instructions) {
     (intervals, instructions)
 }
 
-fn convert_seed_range(range_map: &RangeMap, seed_range: &IntervalSet<i64>) -> IntervalSet<i64> {
-    let mut result = IntervalSet::new();
-    for interval in seed_range.get().iter() {
-        result = result.join(&range_map.convert_interval(interval));
-    }
-    result
-}
-
-fn get_seed_range_locations(
-    instructions: &Instructions,
-    seed_range: &IntervalSet<i64>,
-) -> IntervalSet<i64> {
-    convert_seed_range(
-        instructions.humidity2location(),
-        &convert_seed_range(
-            instructions.temperature2humidity(),
-            &convert_seed_range(
-                instructions.light2temperature(),
-                &convert_seed_range(
-                    instructions.water2light(),
-                    &convert_seed_range(
-                        instructions.fertilizer2water(),
-                        &convert_seed_range(
-                            instructions.soil2fertilizer(),
-                            &convert_seed_range(instructions.seed2soil(), seed_range),
-                        ),
-                    ),
-                ),
-            ),
-        ),
-    )
-}
-
 /// Solves Part 2 of the puzzle
 ///
 /// # Arguments
@@ -165,6 +115,6 @@ fn get_seed_range_locations(
 /// The solution as a string
 pub fn solve_part2(params: Part2Parameters) -> String {
     let (seeds, instructions) = parse_input_for_part2(params.input_data);
-    let locations = get_seed_range_locations(&instructions, &seeds);
+    let locations = instructions.seed2location().map_set(&seeds);
     locations.get()[0].get_min().to_string()
 }
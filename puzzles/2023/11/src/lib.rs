@@ -2,8 +2,8 @@ mod galaxy_map;
 
 use aoc_geometry::Point;
 use aoc_geometry::Vector;
+use aoc_utils::combinatorics::subsets;
 use galaxy_map::GalaxyMap;
-use itertools::Itertools;
 
 const GALAXY_CHARACTER: char = '#';
 
@@ -53,23 +53,19 @@ fn count_empty_between(empty_indices: &[usize], a: usize, b: usize) -> usize {
 
 fn calculate_accumulated_shortest_paths(galaxy_map: &GalaxyMap, empty_multiplier: u64) -> u64 {
     let mut accum_shortest_paths: u64 = 0;
-    galaxy_map
-        .get_galaxies()
-        .iter()
-        .combinations(2)
-        .for_each(|galaxy_combination| {
-            let (g1, g2) = (galaxy_combination[0], galaxy_combination[1]);
-            let distance = Vector::<i64, 2>::from_points(g1, g2)
-                .unwrap()
-                .manhattan_distance();
-            let num_empty_rows =
-                count_empty_between(galaxy_map.get_empty_rows(), *g1.get(1), *g2.get(1));
-            let num_empty_cols =
-                count_empty_between(galaxy_map.get_empty_columns(), *g1.get(0), *g2.get(0));
-            accum_shortest_paths += distance
-                + (num_empty_rows as u64 * (empty_multiplier - 1))
-                + (num_empty_cols as u64 * (empty_multiplier - 1));
-        });
+    subsets(galaxy_map.get_galaxies(), 2).for_each(|galaxy_combination| {
+        let (g1, g2) = (galaxy_combination[0], galaxy_combination[1]);
+        let distance = Vector::<i64, 2>::from_points(g1, g2)
+            .unwrap()
+            .manhattan_distance();
+        let num_empty_rows =
+            count_empty_between(galaxy_map.get_empty_rows(), *g1.get(1), *g2.get(1));
+        let num_empty_cols =
+            count_empty_between(galaxy_map.get_empty_columns(), *g1.get(0), *g2.get(0));
+        accum_shortest_paths += distance
+            + (num_empty_rows as u64 * (empty_multiplier - 1))
+            + (num_empty_cols as u64 * (empty_multiplier - 1));
+    });
     accum_shortest_paths
 }
 
@@ -1,3 +1,5 @@
+use aoc_utils::cache::memo::Memo;
+
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
 enum ExpandedSpringStatus {
     OneDamaged,
@@ -26,6 +28,40 @@ fn expand_contiguous_group_info(group_info: &[i32], repetitions: i32) -> Vec<Exp
     result
 }
 
+/// Counts the ways to match the springs with the expanded group info.
+fn count_arrangements(springs: &[char], group_info: &[ExpandedSpringStatus]) -> u64 {
+    fn count(
+        memo: &mut Memo<(usize, usize), u64>,
+        springs: &[char],
+        group_info: &[ExpandedSpringStatus],
+        i: usize,
+        j: usize,
+    ) -> u64 {
+        let (n, m) = (springs.len(), group_info.len());
+        if i == n {
+            return u64::from(j == m);
+        }
+        if j == m || m - j > n - i {
+            return 0;
+        }
+        memo.get_or_compute((i, j), |memo| {
+            if springs[i] != OPERATIONAL_SPRING && group_info[j] == ExpandedSpringStatus::OneDamaged
+            {
+                count(memo, springs, group_info, i + 1, j + 1)
+            } else if springs[i] != DAMAGED_SPRING
+                && group_info[j] == ExpandedSpringStatus::GroupOfOperational
+            {
+                count(memo, springs, group_info, i + 1, j + 1)
+                    + count(memo, springs, group_info, i + 1, j)
+            } else {
+                0
+            }
+        })
+    }
+
+    count(&mut Memo::new(), springs, group_info, 0, 0)
+}
+
 impl Record {
     pub fn new(springs: String, contiguous_group_info: Vec<i32>) -> Self {
         Self {
@@ -42,29 +78,7 @@ impl Record {
         // translate groupInfo
         let expanded_group_info = expand_contiguous_group_info(&self.contiguous_group_info, 1);
 
-        // solve
-        let n = extended_springs.len();
-        let m = expanded_group_info.len();
-        let mut dp = vec![vec![0u64; m + 1]; n + 1];
-        dp[n][m] = 1;
-        for i in (0..n).rev() {
-            let start_j = m.saturating_sub(n - i);
-            for j in (start_j..m).rev() {
-                let mut value: u64 = 0;
-                if extended_springs[i] != OPERATIONAL_SPRING
-                    && expanded_group_info[j] == ExpandedSpringStatus::OneDamaged
-                {
-                    value = dp[i + 1][j + 1];
-                } else if extended_springs[i] != DAMAGED_SPRING
-                    && expanded_group_info[j] == ExpandedSpringStatus::GroupOfOperational
-                {
-                    value = dp[i + 1][j + 1] + dp[i + 1][j];
-                }
-                dp[i][j] = value;
-            }
-        }
-
-        dp[0][0]
+        count_arrangements(&extended_springs, &expanded_group_info)
     }
 
     pub fn solve_unfolded(&self) -> u64 {
@@ -78,28 +92,6 @@ impl Record {
         // translate groupInfo
         let expanded_group_info = expand_contiguous_group_info(&self.contiguous_group_info, 5);
 
-        // solve
-        let n = extended_springs.len();
-        let m = expanded_group_info.len();
-        let mut dp = vec![vec![0u64; m + 1]; n + 1];
-        dp[n][m] = 1;
-        for i in (0..n).rev() {
-            let start_j = m.saturating_sub(n - i);
-            for j in (start_j..m).rev() {
-                let mut value: u64 = 0;
-                if extended_springs[i] != OPERATIONAL_SPRING
-                    && expanded_group_info[j] == ExpandedSpringStatus::OneDamaged
-                {
-                    value = dp[i + 1][j + 1];
-                } else if extended_springs[i] != DAMAGED_SPRING
-                    && expanded_group_info[j] == ExpandedSpringStatus::GroupOfOperational
-                {
-                    value = dp[i + 1][j + 1] + dp[i + 1][j];
-                }
-                dp[i][j] = value;
-            }
-        }
-
-        dp[0][0]
+        count_arrangements(&extended_springs, &expanded_group_info)
     }
 }
@@ -1,6 +1,7 @@
 mod rocks;
 
 use aoc_geometry::Grid2D;
+use aoc_utils::cache::cycle::find_cycle;
 use rocks::{GridCell, Rocks};
 
 fn parse_input(input: &str) -> Rocks {
@@ -56,7 +57,11 @@ fn execute_one_cycle(rocks: &mut Rocks) {
 ///
 /// The solution as a string
 pub fn solve_part2(params: Part2Parameters) -> String {
-    let mut rocks = parse_input(params.input_data);
-    aoc_utils::cache::loop_cache::run(&mut rocks, 1_000_000_000, execute_one_cycle);
-    rocks.calculate_load().to_string()
+    let rocks = parse_input(params.input_data);
+    let cycle = find_cycle(rocks, |rocks| {
+        let mut rocks = rocks.clone();
+        execute_one_cycle(&mut rocks);
+        rocks
+    });
+    cycle.state_at(1_000_000_000).calculate_load().to_string()
 }
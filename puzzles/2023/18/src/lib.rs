@@ -1,8 +1,7 @@
 mod instruction;
 
-use aoc_geometry::{CardinalDirection2D, OrthogonalPolygon2D, Point, Vector};
+use aoc_geometry::{CardinalDirection2D, OrthogonalPolygon2D, Point};
 use instruction::Instruction;
-use std::ops::Mul;
 
 const DIMENSIONS: usize = 2;
 
@@ -13,24 +12,15 @@ where
     input.lines().map(parse_instruction).collect()
 }
 
-fn solve(instructions: &[Instruction]) -> u64 {
+fn solve(instructions: &[Instruction]) -> u128 {
     let starting_point = Point::<i64, DIMENSIONS>::origin();
-    /* convert instructions into vertexes of a polygon */
-    let vertexes: Vec<Point<i64, 2>> = instructions
-        .iter()
-        .scan(starting_point, |current_point, instruction| {
-            let movement: Vector<i64, DIMENSIONS> = instruction
-                .direction()
-                .to_vector()
-                .mul(instruction.steps() as i32)
-                .unwrap();
-            *current_point = current_point.move_by(&movement).unwrap();
-            Some(*current_point)
-        })
-        .collect();
-    /* generate result */
-    let polygon = OrthogonalPolygon2D::from_vertices(vertexes);
-    polygon.number_of_intrinsic_points() + polygon.perimeter()
+    let polygon = OrthogonalPolygon2D::from_steps(
+        &starting_point,
+        instructions
+            .iter()
+            .map(|instruction| (*instruction.direction(), instruction.steps())),
+    );
+    polygon.number_of_covered_points()
 }
 
 /// Parameters for solving Part 1 of the puzzle.
@@ -40,13 +30,7 @@ pub struct Part1Parameters {
 
 fn parse_instruction_for_part1(line: &str) -> Instruction {
     let parts = line.split_whitespace().collect::<Vec<_>>();
-    let direction = match parts[0] {
-        "R" => CardinalDirection2D::Right,
-        "L" => CardinalDirection2D::Left,
-        "U" => CardinalDirection2D::Up,
-        "D" => CardinalDirection2D::Down,
-        _ => unreachable!(),
-    };
+    let direction = parts[0].parse::<CardinalDirection2D>().unwrap();
     let steps = parts[1].parse::<u64>().unwrap();
     Instruction::new(direction, steps)
 }
@@ -131,10 +131,10 @@ fn parse_system_for_part2(input_data: &str) -> part2::system::System {
 pub fn solve_part2(params: Part2Parameters) -> String {
     let input_data_parts: Vec<_> = params.input_data.split("\n\n").collect();
     let system = parse_system_for_part2(input_data_parts[0]);
-    let num_combinations: u64 = system
+    let num_combinations: u128 = system
         .search()
         .iter()
-        .map(|part_range| part_range.calculate_number_of_combinations())
+        .map(|part_range| part_range.count())
         .sum();
     num_combinations.to_string()
 }
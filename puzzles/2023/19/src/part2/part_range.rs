@@ -1,58 +1,23 @@
 use aoc_intervals::interval::Interval;
+use aoc_intervals::interval_box::IntervalBox;
 
-pub type Range = Interval<i32>;
+/// Number of categories of a part rating: x, m, a and s.
+const NUM_CATEGORIES: usize = 4;
 
-#[derive(Eq, PartialEq, Hash, Debug, Clone)]
-pub struct PartRange {
-    x: Range,
-    m: Range,
-    a: Range,
-    s: Range,
-}
-
-impl PartRange {
-    pub fn new() -> Self {
-        Self {
-            x: Range::from_boundaries(1, 4000),
-            m: Range::from_boundaries(1, 4000),
-            a: Range::from_boundaries(1, 4000),
-            s: Range::from_boundaries(1, 4000),
-        }
-    }
-
-    pub fn get_x(&self) -> &Range {
-        &self.x
-    }
+pub type PartRange = IntervalBox<i32, NUM_CATEGORIES>;
 
-    pub fn set_x(&mut self, new_value: &Range) {
-        self.x = *new_value;
-    }
-
-    pub fn get_m(&self) -> &Range {
-        &self.m
-    }
-
-    pub fn set_m(&mut self, new_value: &Range) {
-        self.m = *new_value;
-    }
-
-    pub fn get_a(&self) -> &Range {
-        &self.a
-    }
-
-    pub fn set_a(&mut self, new_value: &Range) {
-        self.a = *new_value;
-    }
-
-    pub fn get_s(&self) -> &Range {
-        &self.s
-    }
-
-    pub fn set_s(&mut self, new_value: &Range) {
-        self.s = *new_value;
-    }
+/// Creates the range of all the possible parts, where every rating goes from 1 to 4000.
+pub fn full_part_range() -> PartRange {
+    PartRange::new([Interval::from_boundaries(1, 4000); NUM_CATEGORIES])
+}
 
-    pub fn calculate_number_of_combinations(&self) -> u64 {
-        self.x.count() * self.m.count() * self.a.count() * self.s.count()
+/// Gets the axis of the `PartRange` associated to a category.
+pub fn category_axis(category: &str) -> usize {
+    match category {
+        "x" => 0,
+        "m" => 1,
+        "a" => 2,
+        "s" => 3,
+        _ => unreachable!(),
     }
 }
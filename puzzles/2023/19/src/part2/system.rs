@@ -1,4 +1,4 @@
-use crate::part2::part_range::PartRange;
+use crate::part2::part_range::{PartRange, full_part_range};
 use crate::part2::rule::ActionType;
 use crate::part2::workflow::Workflow;
 use std::collections::HashMap;
@@ -15,7 +15,7 @@ fn search_accepted(
 ) {
     match action_type {
         ActionType::Accepted => {
-            accepted.push(value.clone());
+            accepted.push(*value);
         }
         ActionType::Rejected => {
             // do nothing
@@ -64,7 +64,7 @@ impl System {
             &self.workflows,
             &mut accepted,
             &ActionType::GoTo(String::from("in")),
-            &mut PartRange::new(),
+            &mut full_part_range(),
         );
         accepted
     }